@@ -0,0 +1,74 @@
+//! Compares the SIMD `batch` slice operations against plain per-element loops.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use vec3_rs::{batch, Vector3};
+
+const LEN: usize = 1 << 12;
+const ROUNDS: u32 = 2000;
+
+fn measure<F: FnMut()>(name: &str, mut f: F) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let elapsed = start.elapsed() / ROUNDS;
+    println!("{name:<24} {elapsed:?}");
+    elapsed
+}
+
+fn main() {
+    let a: Vec<Vector3<f32>> = (0..LEN)
+        .map(|i| Vector3::new(i as f32, 1.0 - i as f32, 0.5 * i as f32))
+        .collect();
+    let b: Vec<Vector3<f32>> = a.iter().rev().copied().collect();
+    let mut out = vec![Vector3::zero(); LEN];
+
+    let scalar = measure("add (scalar loop)", || {
+        for ((a, b), out) in a.iter().zip(&b).zip(out.iter_mut()) {
+            *out = *a + *b;
+        }
+        black_box(&out);
+    });
+    let batched = measure("add (batch)", || {
+        batch::add_slices(black_box(&a), black_box(&b), &mut out);
+        black_box(&out);
+    });
+    println!(
+        "add speedup: {:.2}x",
+        scalar.as_secs_f64() / batched.as_secs_f64()
+    );
+
+    let scalar = measure("dot (scalar loop)", || {
+        let dots: Vec<f32> = a.iter().zip(&b).map(|(a, b)| a.dot(b)).collect();
+        black_box(dots);
+    });
+    let batched = measure("dot (batch)", || {
+        black_box(batch::dot_slices(black_box(&a), black_box(&b)));
+    });
+    println!(
+        "dot speedup: {:.2}x",
+        scalar.as_secs_f64() / batched.as_secs_f64()
+    );
+
+    let scalar = measure("sum (scalar loop)", || {
+        let total = a.iter().fold(Vector3::zero(), |acc: Vector3<f64>, p| {
+            acc + Vector3::new(
+                f64::from(p.get_x()),
+                f64::from(p.get_y()),
+                f64::from(p.get_z()),
+            )
+        });
+        black_box(total);
+    });
+    let batched = measure("sum (batch)", || {
+        black_box(batch::sum(black_box(&a)));
+    });
+    println!(
+        "sum speedup: {:.2}x",
+        scalar.as_secs_f64() / batched.as_secs_f64()
+    );
+}
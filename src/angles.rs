@@ -0,0 +1,231 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::{consts, Vector3};
+use std::f64::consts::{PI, TAU};
+
+impl Vector3<f64> {
+    /// Converts this vector to spherical coordinates `(radius, theta, phi)` in radians.
+    ///
+    /// `theta` is the inclination measured from the +Z axis in `[0, π]` and `phi`
+    /// is the azimuth in the XY plane measured from the +X axis in `[-π, π]`.
+    /// The zero vector maps to `(0.0, 0.0, 0.0)`.
+    pub fn to_spherical(&self) -> (f64, f64, f64) {
+        let radius = self.magnitude();
+        if radius == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+        let theta = (self.z / radius).clamp(-1.0, 1.0).acos();
+        let phi = self.y.atan2(self.x);
+        (radius, theta, phi)
+    }
+
+    /// Creates a vector from spherical coordinates in radians, using the convention
+    /// of [`Vector3::to_spherical`].
+    pub fn from_spherical(radius: f64, theta: f64, phi: f64) -> Vector3<f64> {
+        Vector3 {
+            x: radius * theta.sin() * phi.cos(),
+            y: radius * theta.sin() * phi.sin(),
+            z: radius * theta.cos(),
+        }
+    }
+
+    /// Converts this vector to spherical coordinates `(radius, theta, phi)` with the
+    /// angles in degrees. See [`Vector3::to_spherical`].
+    pub fn to_spherical_deg(&self) -> (f64, f64, f64) {
+        let (radius, theta, phi) = self.to_spherical();
+        (radius, theta.to_degrees(), phi.to_degrees())
+    }
+
+    /// Creates a vector from spherical coordinates with the angles in degrees.
+    /// See [`Vector3::from_spherical`].
+    pub fn from_spherical_deg(radius: f64, theta_deg: f64, phi_deg: f64) -> Vector3<f64> {
+        Vector3::from_spherical(radius, theta_deg.to_radians(), phi_deg.to_radians())
+    }
+
+    /// Creates a unit direction from yaw and pitch angles in radians, following the
+    /// crate's Y-up, +Z forward convention (see [`consts`]).
+    ///
+    /// A yaw and pitch of zero point along +Z. Positive `yaw_rad` turns about +Y
+    /// from +Z towards +X, and positive `pitch_rad` tilts up towards +Y.
+    pub fn from_yaw_pitch(yaw_rad: f64, pitch_rad: f64) -> Vector3<f64> {
+        Vector3 {
+            x: pitch_rad.cos() * yaw_rad.sin(),
+            y: pitch_rad.sin(),
+            z: pitch_rad.cos() * yaw_rad.cos(),
+        }
+    }
+
+    /// Converts this direction into `(yaw, pitch)` in radians, the inverse of
+    /// [`Vector3::from_yaw_pitch`]. The vector need not be unit length.
+    ///
+    /// `yaw` lies in `[-π, π]` and `pitch` in `[-π/2, π/2]`. Straight up or down
+    /// (and the zero vector) the yaw is undefined and reported as `0.0`.
+    pub fn to_yaw_pitch(&self) -> (f64, f64) {
+        let length = self.magnitude();
+        if length == 0.0 {
+            return (0.0, 0.0);
+        }
+        let pitch = (self.y / length).clamp(-1.0, 1.0).asin();
+        let yaw = if self.x == 0.0 && self.z == 0.0 {
+            0.0
+        } else {
+            self.x.atan2(self.z)
+        };
+        (yaw, pitch)
+    }
+
+    /// Computes the angle in radians between this vector and the X-axis.
+    pub fn angle_to_x(&self) -> f64 {
+        self.angle(&consts::X_AXIS)
+    }
+
+    /// Computes the angle in radians between this vector and the Y-axis.
+    pub fn angle_to_y(&self) -> f64 {
+        self.angle(&consts::Y_AXIS)
+    }
+
+    /// Computes the angle in radians between this vector and the Z-axis.
+    pub fn angle_to_z(&self) -> f64 {
+        self.angle(&consts::Z_AXIS)
+    }
+
+    /// Wraps each component, interpreted as an angle in radians, into `[-π, π]`.
+    pub fn wrap_angles(&self) -> Vector3<f64> {
+        Vector3 {
+            x: wrap_angle(self.x),
+            y: wrap_angle(self.y),
+            z: wrap_angle(self.z),
+        }
+    }
+
+    /// Computes the shortest signed angular difference from each component of this
+    /// vector to the matching component of `other`, wrapped into `[-π, π]`.
+    ///
+    /// Adding the result to `self` reaches `other` modulo a full turn.
+    pub fn angle_diff(&self, other: &Self) -> Vector3<f64> {
+        (*other - *self).wrap_angles()
+    }
+
+    /// Interpolates each component, interpreted as an angle in radians, towards
+    /// `target` along the shortest angular path. The result is wrapped into `[-π, π]`.
+    pub fn lerp_angles(&self, target: &Self, alpha: f64) -> Vector3<f64> {
+        (*self + self.angle_diff(target) * alpha).wrap_angles()
+    }
+}
+
+/// Wraps an angle in radians into `[-π, π]`.
+fn wrap_angle(angle: f64) -> f64 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spherical_axes() {
+        assert_eq!(consts::Z_AXIS.to_spherical(), (1.0, 0.0, 0.0));
+        assert_eq!(consts::VECTOR3_ZERO.to_spherical(), (0.0, 0.0, 0.0));
+        let (radius, theta, phi) = (consts::Y_AXIS * 2.0).to_spherical_deg();
+        assert_eq!((radius, theta, phi), (2.0, 90.0, 90.0));
+    }
+
+    #[test]
+    fn spherical_degrees_round_trip() {
+        let vec = Vector3::from_spherical_deg(3.0, 60.0, -135.0);
+        let (radius, theta, phi) = vec.to_spherical_deg();
+        assert!((radius - 3.0).abs() < 1e-12);
+        assert!((theta - 60.0).abs() < 1e-12);
+        assert!((phi + 135.0).abs() < 1e-12);
+
+        let original = Vector3::new(1.0, -2.0, 0.5);
+        let (radius, theta, phi) = original.to_spherical_deg();
+        let restored = Vector3::from_spherical_deg(radius, theta, phi);
+        assert!(restored.fuzzy_equal(&original, 1e-12));
+    }
+
+    #[test]
+    fn yaw_pitch_axes() {
+        let forward = Vector3::from_yaw_pitch(0.0, 0.0);
+        assert!(forward.fuzzy_equal(&consts::FORWARD, 1e-12));
+        let right = Vector3::from_yaw_pitch(PI / 2.0, 0.0);
+        assert!(right.fuzzy_equal(&consts::RIGHT, 1e-12));
+        let up = Vector3::from_yaw_pitch(0.3, PI / 2.0);
+        assert!(up.fuzzy_equal(&consts::UP, 1e-12));
+    }
+
+    #[test]
+    fn yaw_pitch_round_trip() {
+        for &(yaw, pitch) in &[
+            (0.0, 0.0),
+            (1.0, 0.5),
+            (-2.5, -1.2),
+            (PI, 0.25),
+            (0.1, -0.1),
+        ] {
+            let direction = Vector3::from_yaw_pitch(yaw, pitch);
+            assert!((direction.magnitude() - 1.0).abs() < 1e-12);
+            let (yaw_back, pitch_back) = direction.to_yaw_pitch();
+            assert!((yaw_back - yaw).abs() < 1e-12);
+            assert!((pitch_back - pitch).abs() < 1e-12);
+        }
+        let (yaw, pitch) = Vector3::new(3.0, 0.0, 3.0).to_yaw_pitch();
+        assert!((yaw - PI / 4.0).abs() < 1e-12);
+        assert_eq!(pitch, 0.0);
+    }
+
+    #[test]
+    fn yaw_pitch_poles() {
+        assert_eq!(consts::UP.to_yaw_pitch(), (0.0, PI / 2.0));
+        assert_eq!(consts::DOWN.to_yaw_pitch(), (0.0, -PI / 2.0));
+        assert_eq!(Vector3::new(0.0, 5.0, 0.0).to_yaw_pitch(), (0.0, PI / 2.0));
+        assert_eq!(consts::VECTOR3_ZERO.to_yaw_pitch(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn angles_to_axes() {
+        assert_eq!(consts::X_AXIS.angle_to_x(), 0.0);
+        assert_eq!(consts::Y_AXIS.angle_to_x(), PI / 2.0);
+        assert_eq!(consts::Y_AXIS.angle_to_y(), 0.0);
+        assert_eq!(consts::NEG_Z_AXIS.angle_to_z(), PI);
+
+        let diagonal = Vector3::new(1.0, 1.0, 0.0);
+        assert!((diagonal.angle_to_x() - PI / 4.0).abs() < 1e-12);
+        assert!((diagonal.angle_to_y() - PI / 4.0).abs() < 1e-12);
+        assert_eq!(diagonal.angle_to_z(), PI / 2.0);
+    }
+
+    #[test]
+    fn wrapped_angles() {
+        let angles = Vector3::new(3.0 * PI, -3.0 * PI, PI / 2.0);
+        let wrapped = angles.wrap_angles();
+        assert!((wrapped.x.abs() - PI).abs() < 1e-12);
+        assert!((wrapped.y.abs() - PI).abs() < 1e-12);
+        assert_eq!(wrapped.z, PI / 2.0);
+
+        let wrapped = Vector3::new(TAU + 0.5, -TAU - 0.5, -0.25).wrap_angles();
+        assert!(wrapped.fuzzy_equal(&Vector3::new(0.5, -0.5, -0.25), 1e-12));
+    }
+
+    #[test]
+    fn angle_difference() {
+        let near_zero = Vector3::new(0.1, 0.0, 0.0);
+        let near_full_turn = Vector3::new(TAU - 0.1, 0.0, 0.0);
+        let diff = near_zero.angle_diff(&near_full_turn);
+        assert!(diff.fuzzy_equal(&Vector3::new(-0.2, 0.0, 0.0), 1e-12));
+        let diff = near_full_turn.angle_diff(&near_zero);
+        assert!(diff.fuzzy_equal(&Vector3::new(0.2, 0.0, 0.0), 1e-12));
+    }
+
+    #[test]
+    fn angle_interpolation() {
+        let start = Vector3::new(PI - 0.1, 0.0, 1.0);
+        let end = Vector3::new(-PI + 0.3, 0.0, 2.0);
+        let quarter = start.lerp_angles(&end, 0.25);
+        assert!((quarter.x.abs() - PI).abs() < 1e-12);
+        assert_eq!((quarter.y, quarter.z), (0.0, 1.25));
+        let half = start.lerp_angles(&end, 0.5);
+        assert!(half.fuzzy_equal(&Vector3::new(-PI + 0.1, 0.0, 1.5), 1e-12));
+        assert!(start.lerp_angles(&end, 1.0).fuzzy_equal(&end, 1e-12));
+    }
+}
@@ -0,0 +1,83 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::{Vector3, Vector3Coordinate};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl<T> AbsDiffEq for Vector3<T>
+where
+    T: Vector3Coordinate + num::Float + AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.z.abs_diff_eq(&other.z, epsilon)
+    }
+}
+
+impl<T> RelativeEq for Vector3<T>
+where
+    T: Vector3Coordinate + num::Float + RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.z.relative_eq(&other.z, epsilon, max_relative)
+    }
+}
+
+impl<T> UlpsEq for Vector3<T>
+where
+    T: Vector3Coordinate + num::Float + UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, epsilon, max_ulps)
+            && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+            && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+    #[test]
+    fn relative_equality() {
+        let sum: Vector3<f64> = Vector3::new(0.1, 0.2, 0.3) + Vector3::new(0.2, 0.1, 0.0);
+        assert_ne!(sum, Vector3::new(0.3, 0.3, 0.3));
+        assert_relative_eq!(sum, Vector3::new(0.3, 0.3, 0.3));
+        assert_relative_ne!(sum, Vector3::new(0.3, 0.3, 0.31));
+        assert_relative_eq!(
+            Vector3::new(100.0f32, 200.0, 300.0),
+            Vector3::new(101.0, 202.0, 303.0),
+            max_relative = 0.01
+        );
+    }
+
+    #[test]
+    fn abs_diff_and_ulps_equality() {
+        let vec: Vector3<f32> = Vector3::new(1.0, 2.0, 3.0);
+        assert_abs_diff_eq!(vec, Vector3::new(1.05, 1.95, 3.0), epsilon = 0.1);
+        assert!(vec.abs_diff_ne(&Vector3::new(1.5, 2.0, 3.0), 0.1));
+        assert_ulps_eq!(vec * 0.1 * 10.0, vec);
+    }
+}
@@ -0,0 +1,70 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::{Vector3, Vector3Coordinate};
+
+/// One of the three coordinate axes, for selecting a component without a raw index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    /// Creates the unit vector pointing along this axis.
+    pub fn unit_vector<T: Vector3Coordinate>(self) -> Vector3<T> {
+        match self {
+            Axis::X => Vector3::unit_x(),
+            Axis::Y => Vector3::unit_y(),
+            Axis::Z => Vector3::unit_z(),
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> Vector3<T> {
+    /// Retrieves the component along `axis`.
+    pub fn component(&self, axis: Axis) -> T {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+            Axis::Z => self.z,
+        }
+    }
+
+    /// Sets the component along `axis` to `value`.
+    pub fn set_component(&mut self, axis: Axis, value: T) {
+        match axis {
+            Axis::X => self.x = value,
+            Axis::Y => self.y = value,
+            Axis::Z => self.z = value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_and_set_components() {
+        let mut vec = Vector3::new(1, 2, 3);
+        assert_eq!(vec.component(Axis::X), 1);
+        assert_eq!(vec.component(Axis::Y), 2);
+        assert_eq!(vec.component(Axis::Z), 3);
+
+        vec.set_component(Axis::X, 10);
+        vec.set_component(Axis::Y, 20);
+        vec.set_component(Axis::Z, 30);
+        assert_eq!(vec, Vector3::new(10, 20, 30));
+    }
+
+    #[test]
+    fn unit_vectors() {
+        assert_eq!(Axis::X.unit_vector::<f64>(), crate::consts::X_AXIS);
+        assert_eq!(Axis::Y.unit_vector(), Vector3::new(0, 1, 0));
+        assert_eq!(Axis::Z.unit_vector(), Vector3::new(0.0f32, 0.0, 1.0));
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            assert_eq!(axis.unit_vector::<i32>().component(axis), 1);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::Vector3;
+use wide::{f32x4, f64x4};
+
+// The slices are processed four vectors at a time. Four `Vector3<f32>` are twelve
+// consecutive floats, which load into three `f32x4` registers without reordering:
+//
+//     [x0 y0 z0 x1] [y1 z1 x2 y2] [z2 x3 y3 z3]
+//
+// Component-wise operations work on that layout directly. The fewer than four
+// vectors left over at the end go through the scalar code.
+const LANES: usize = 4;
+
+/// Loads four vectors into three SIMD registers in memory order.
+fn load(chunk: &[Vector3<f32>]) -> [f32x4; 3] {
+    let [a, b, c, d] = [chunk[0], chunk[1], chunk[2], chunk[3]];
+    [
+        f32x4::new([a.x, a.y, a.z, b.x]),
+        f32x4::new([b.y, b.z, c.x, c.y]),
+        f32x4::new([c.z, d.x, d.y, d.z]),
+    ]
+}
+
+/// Stores three SIMD registers back into four vectors, the inverse of [`load`].
+fn store(registers: [f32x4; 3], chunk: &mut [Vector3<f32>]) {
+    let [r0, r1, r2] = registers.map(f32x4::to_array);
+    chunk[0] = Vector3::new(r0[0], r0[1], r0[2]);
+    chunk[1] = Vector3::new(r0[3], r1[0], r1[1]);
+    chunk[2] = Vector3::new(r1[2], r1[3], r2[0]);
+    chunk[3] = Vector3::new(r2[1], r2[2], r2[3]);
+}
+
+/// Adds `a` and `b` element-wise, writing the sums into `out`.
+///
+/// # Panics
+///
+/// Panics if the three slices differ in length.
+pub fn add_slices(a: &[Vector3<f32>], b: &[Vector3<f32>], out: &mut [Vector3<f32>]) {
+    assert_eq!(a.len(), b.len(), "input slices must have the same length");
+    assert_eq!(
+        a.len(),
+        out.len(),
+        "output slice must match the input length"
+    );
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let (a_rest, b_rest) = (a_chunks.remainder(), b_chunks.remainder());
+    let mut out_chunks = out.chunks_exact_mut(LANES);
+    for ((a, b), out) in a_chunks.zip(b_chunks).zip(&mut out_chunks) {
+        let [a0, a1, a2] = load(a);
+        let [b0, b1, b2] = load(b);
+        store([a0 + b0, a1 + b1, a2 + b2], out);
+    }
+    let out_rest = out_chunks.into_remainder();
+    for ((a, b), out) in a_rest.iter().zip(b_rest).zip(out_rest) {
+        *out = *a + *b;
+    }
+}
+
+/// Computes the dot product of every pair of vectors in `a` and `b`.
+///
+/// The products are computed with SIMD and summed per vector as `x + y + z`, so
+/// the results are identical to [`Vector3::dot`].
+///
+/// # Panics
+///
+/// Panics if the slices differ in length.
+pub fn dot_slices(a: &[Vector3<f32>], b: &[Vector3<f32>]) -> Vec<f32> {
+    assert_eq!(a.len(), b.len(), "input slices must have the same length");
+    let mut dots = Vec::with_capacity(a.len());
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let (a_rest, b_rest) = (a_chunks.remainder(), b_chunks.remainder());
+    for (a, b) in a_chunks.zip(b_chunks) {
+        let [a0, a1, a2] = load(a);
+        let [b0, b1, b2] = load(b);
+        let [p0, p1, p2] = [a0 * b0, a1 * b1, a2 * b2].map(f32x4::to_array);
+        dots.extend([
+            p0[0] + p0[1] + p0[2],
+            p0[3] + p1[0] + p1[1],
+            p1[2] + p1[3] + p2[0],
+            p2[1] + p2[2] + p2[3],
+        ]);
+    }
+    dots.extend(a_rest.iter().zip(b_rest).map(|(a, b)| a.dot(b)));
+    dots
+}
+
+/// Sums all points, accumulating in `f64` to avoid the precision loss of an `f32`
+/// running total over large inputs.
+///
+/// The points are widened and added four components at a time into `f64x4`
+/// accumulators, so the rounding can differ slightly from a strictly sequential
+/// sum.
+pub fn sum(points: &[Vector3<f32>]) -> Vector3<f64> {
+    let widen = |r: f32x4| f64x4::new(r.to_array().map(f64::from));
+
+    let chunks = points.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    let mut accumulators = [f64x4::ZERO; 3];
+    for chunk in chunks {
+        for (accumulator, register) in accumulators.iter_mut().zip(load(chunk)) {
+            *accumulator += widen(register);
+        }
+    }
+    // Each lane always holds the same component, following the layout of `load`.
+    let [r0, r1, r2] = accumulators.map(f64x4::to_array);
+    let mut total = Vector3::new(
+        r0[0] + r0[3] + r1[2] + r2[1],
+        r0[1] + r1[0] + r1[3] + r2[2],
+        r0[2] + r1[1] + r2[0] + r2[3],
+    );
+    for point in remainder {
+        total += Vector3::new(f64::from(point.x), f64::from(point.y), f64::from(point.z));
+    }
+    total
+}
+
+/// Computes the centroid (mean position) of the points, or `None` for an empty
+/// slice. See [`sum`] for the accumulation precision.
+pub fn centroid(points: &[Vector3<f32>]) -> Option<Vector3<f64>> {
+    if points.is_empty() {
+        return None;
+    }
+    Some(sum(points) / points.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn random_vectors(rng: &mut StdRng, len: usize) -> Vec<Vector3<f32>> {
+        (0..len)
+            .map(|_| {
+                Vector3::new(
+                    rng.gen_range(-100.0..100.0),
+                    rng.gen_range(-100.0..100.0),
+                    rng.gen_range(-100.0..100.0),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn add_slices_matches_scalar() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for len in [0, 5, 8, 37] {
+            let a = random_vectors(&mut rng, len);
+            let b = random_vectors(&mut rng, len);
+            let mut out = vec![Vector3::zero(); len];
+            add_slices(&a, &b, &mut out);
+            let expected: Vec<_> = a.iter().zip(&b).map(|(a, b)| *a + *b).collect();
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn dot_slices_matches_scalar() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for len in [0, 3, 16, 29] {
+            let a = random_vectors(&mut rng, len);
+            let b = random_vectors(&mut rng, len);
+            let expected: Vec<_> = a.iter().zip(&b).map(|(a, b)| a.dot(b)).collect();
+            assert_eq!(dot_slices(&a, &b), expected);
+        }
+    }
+
+    #[test]
+    fn sum_matches_naive() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let points = random_vectors(&mut rng, 100_003);
+        let naive = points.iter().fold(Vector3::zero(), |acc: Vector3<f64>, p| {
+            acc + Vector3::new(f64::from(p.x), f64::from(p.y), f64::from(p.z))
+        });
+        assert!(sum(&points).fuzzy_equal(&naive, 1e-6));
+        assert!(centroid(&points)
+            .unwrap()
+            .fuzzy_equal(&(naive / points.len() as f64), 1e-12));
+    }
+
+    #[test]
+    fn centroid_small() {
+        let points = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(2.0, 4.0, -6.0),
+            Vector3::new(1.0, 2.0, 0.0),
+        ];
+        assert_eq!(sum(&points), Vector3::new(3.0, 6.0, -6.0));
+        assert_eq!(centroid(&points), Some(Vector3::new(1.0, 2.0, -2.0)));
+        assert_eq!(centroid(&[]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "input slices must have the same length")]
+    fn mismatched_dot_lengths() {
+        let a = [Vector3::new(1.0, 2.0, 3.0); 2];
+        dot_slices(&a, &a[..1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "output slice must match the input length")]
+    fn mismatched_lengths() {
+        let a = [Vector3::new(1.0, 2.0, 3.0); 2];
+        let mut out = [Vector3::zero(); 3];
+        add_slices(&a, &a, &mut out);
+    }
+}
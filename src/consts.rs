@@ -3,46 +3,154 @@
 use crate::Vector3;
 
 /// Predefined constant vector along the X-axis.
-pub const X_AXIS: Vector3<f64> = Vector3 {
-    x: 1.0,
-    y: 0.0,
-    z: 0.0,
-};
+pub const X_AXIS: Vector3<f64> = Vector3::new(1.0, 0.0, 0.0);
 
 /// Predefined constant vector along the Y-axis.
-pub const Y_AXIS: Vector3<f64> = Vector3 {
-    x: 0.0,
-    y: 1.0,
-    z: 0.0,
-};
+pub const Y_AXIS: Vector3<f64> = Vector3::new(0.0, 1.0, 0.0);
 
 /// Predefined constant vector along the Z-axis.
-pub const Z_AXIS: Vector3<f64> = Vector3 {
-    x: 0.0,
-    y: 0.0,
-    z: 1.0,
-};
+pub const Z_AXIS: Vector3<f64> = Vector3::new(0.0, 0.0, 1.0);
+
+/// Predefined constant vector along the negative X-axis.
+pub const NEG_X_AXIS: Vector3<f64> = Vector3::new(-1.0, 0.0, 0.0);
+
+/// Predefined constant vector along the negative Y-axis.
+pub const NEG_Y_AXIS: Vector3<f64> = Vector3::new(0.0, -1.0, 0.0);
+
+/// Predefined constant vector along the negative Z-axis.
+pub const NEG_Z_AXIS: Vector3<f64> = Vector3::new(0.0, 0.0, -1.0);
 
 /// Predefined constant zero vector.
-pub const VECTOR3_ZERO: Vector3<f64> = Vector3 {
-    x: 0.0,
-    y: 0.0,
-    z: 0.0,
-};
+pub const VECTOR3_ZERO: Vector3<f64> = Vector3::new(0.0, 0.0, 0.0);
 
 /// Predefined constant unit vector.
-pub const VECTOR3_ONE: Vector3<f64> = Vector3 {
-    x: 1.0,
-    y: 1.0,
-    z: 1.0,
-};
+pub const VECTOR3_ONE: Vector3<f64> = Vector3::new(1.0, 1.0, 1.0);
+
+// The direction aliases below follow a left-handed, Y-up convention: +X is right,
+// +Y is up and +Z is forward, matching the camera space of `perspective_project`.
+
+/// Up direction, an alias for [`Y_AXIS`].
+pub const UP: Vector3<f64> = Y_AXIS;
+
+/// Down direction, an alias for [`NEG_Y_AXIS`].
+pub const DOWN: Vector3<f64> = NEG_Y_AXIS;
+
+/// Left direction, an alias for [`NEG_X_AXIS`].
+pub const LEFT: Vector3<f64> = NEG_X_AXIS;
+
+/// Right direction, an alias for [`X_AXIS`].
+pub const RIGHT: Vector3<f64> = X_AXIS;
+
+/// Forward direction, an alias for [`Z_AXIS`].
+pub const FORWARD: Vector3<f64> = Z_AXIS;
+
+/// Back direction, an alias for [`NEG_Z_AXIS`].
+pub const BACK: Vector3<f64> = NEG_Z_AXIS;
+
+/// The same constants as the parent module, for `f32` coordinates.
+pub mod f32 {
+    use crate::Vector3;
+
+    /// Predefined constant vector along the X-axis.
+    pub const X_AXIS: Vector3<f32> = Vector3::new(1.0, 0.0, 0.0);
+
+    /// Predefined constant vector along the Y-axis.
+    pub const Y_AXIS: Vector3<f32> = Vector3::new(0.0, 1.0, 0.0);
+
+    /// Predefined constant vector along the Z-axis.
+    pub const Z_AXIS: Vector3<f32> = Vector3::new(0.0, 0.0, 1.0);
+
+    /// Predefined constant vector along the negative X-axis.
+    pub const NEG_X_AXIS: Vector3<f32> = Vector3::new(-1.0, 0.0, 0.0);
+
+    /// Predefined constant vector along the negative Y-axis.
+    pub const NEG_Y_AXIS: Vector3<f32> = Vector3::new(0.0, -1.0, 0.0);
+
+    /// Predefined constant vector along the negative Z-axis.
+    pub const NEG_Z_AXIS: Vector3<f32> = Vector3::new(0.0, 0.0, -1.0);
+
+    /// Predefined constant zero vector.
+    pub const VECTOR3_ZERO: Vector3<f32> = Vector3::new(0.0, 0.0, 0.0);
+
+    /// Predefined constant unit vector.
+    pub const VECTOR3_ONE: Vector3<f32> = Vector3::new(1.0, 1.0, 1.0);
+
+    /// Up direction, an alias for [`Y_AXIS`].
+    pub const UP: Vector3<f32> = Y_AXIS;
+
+    /// Down direction, an alias for [`NEG_Y_AXIS`].
+    pub const DOWN: Vector3<f32> = NEG_Y_AXIS;
+
+    /// Left direction, an alias for [`NEG_X_AXIS`].
+    pub const LEFT: Vector3<f32> = NEG_X_AXIS;
+
+    /// Right direction, an alias for [`X_AXIS`].
+    pub const RIGHT: Vector3<f32> = X_AXIS;
+
+    /// Forward direction, an alias for [`Z_AXIS`].
+    pub const FORWARD: Vector3<f32> = Z_AXIS;
+
+    /// Back direction, an alias for [`NEG_Z_AXIS`].
+    pub const BACK: Vector3<f32> = NEG_Z_AXIS;
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
     fn const_sum() {
         let one = VECTOR3_ONE;
         assert_eq!(one, VECTOR3_ZERO + X_AXIS + Y_AXIS + Z_AXIS);
+        assert_eq!(one - X_AXIS - Y_AXIS, Z_AXIS);
+        assert_eq!(one - one, VECTOR3_ZERO);
+        assert_eq!(X_AXIS * 2.0 + Y_AXIS * 2.0 + Z_AXIS * 2.0, one * 2.0);
+        assert_eq!(Y_AXIS * 0.0, VECTOR3_ZERO);
+    }
+
+    #[test]
+    fn negative_axes() {
+        assert_eq!(NEG_X_AXIS, -X_AXIS);
+        assert_eq!(NEG_Y_AXIS, -Y_AXIS);
+        assert_eq!(NEG_Z_AXIS, -Z_AXIS);
+        assert_eq!(f32::NEG_X_AXIS, -f32::X_AXIS);
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(UP, -DOWN);
+        assert_eq!(RIGHT, -LEFT);
+        assert_eq!(FORWARD, -BACK);
+        assert_eq!(RIGHT.cross(&UP), FORWARD);
+        assert_eq!(f32::UP, -f32::DOWN);
+    }
+
+    #[test]
+    fn f32_matches_f64() {
+        let cast =
+            |v: Vector3<f64>| Vector3::new(v.get_x() as f32, v.get_y() as f32, v.get_z() as f32);
+        assert_eq!(f32::X_AXIS, cast(X_AXIS));
+        assert_eq!(f32::Y_AXIS, cast(Y_AXIS));
+        assert_eq!(f32::Z_AXIS, cast(Z_AXIS));
+        assert_eq!(f32::NEG_X_AXIS, cast(NEG_X_AXIS));
+        assert_eq!(f32::NEG_Y_AXIS, cast(NEG_Y_AXIS));
+        assert_eq!(f32::NEG_Z_AXIS, cast(NEG_Z_AXIS));
+        assert_eq!(f32::VECTOR3_ZERO, cast(VECTOR3_ZERO));
+        assert_eq!(f32::VECTOR3_ONE, cast(VECTOR3_ONE));
+    }
+
+    #[test]
+    fn const_new() {
+        const FLOAT: Vector3<f64> = Vector3::new(1.0, 2.0, 3.0);
+        const SINGLE: Vector3<f32> = Vector3::new(1.0, 2.0, 3.0);
+        const SIGNED: Vector3<i8> = Vector3::new(-1, 0, 1);
+        const UNSIGNED: Vector3<u128> = Vector3::new(1, 2, u128::MAX);
+        const X: f64 = FLOAT.get_x();
+
+        assert_eq!(FLOAT, Vector3::from([1.0, 2.0, 3.0]));
+        assert_eq!(SINGLE, Vector3::from([1.0, 2.0, 3.0]));
+        assert_eq!(SIGNED, Vector3::from([-1, 0, 1]));
+        assert_eq!(UNSIGNED, Vector3::from([1, 2, u128::MAX]));
+        assert_eq!(X, 1.0);
     }
 }
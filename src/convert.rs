@@ -41,8 +41,8 @@ pub enum ParseVector3Error {
     ParseNumberError(#[from] std::num::ParseFloatError),
     #[error("invalid format")]
     InvalidFormat,
-    #[error("invalid Vec<Number>")]
-    InvalidVec,
+    #[error("expected 3 components, found {found}")]
+    InvalidVec { found: usize },
 }
 
 impl TryFrom<&str> for Vector3<f64> {
@@ -66,13 +66,211 @@ impl TryFrom<&str> for Vector3<f64> {
     }
 }
 
+impl<T: Vector3Coordinate> TryFrom<&[T]> for Vector3<T> {
+    type Error = ParseVector3Error;
+    /// Succeeds only for a slice of exactly three elements.
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        match value {
+            [x, y, z] => Ok(Vector3::new(*x, *y, *z)),
+            _ => Err(ParseVector3Error::InvalidVec { found: value.len() }),
+        }
+    }
+}
+
 impl<T: Vector3Coordinate> TryFrom<Vec<T>> for Vector3<T> {
     type Error = ParseVector3Error;
+    /// Takes the first three elements, failing if there are fewer than three.
     fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
-        let x = value.first().ok_or(ParseVector3Error::InvalidVec)?;
-        let y = value.get(1).ok_or(ParseVector3Error::InvalidVec)?;
-        let z = value.get(2).ok_or(ParseVector3Error::InvalidVec)?;
+        match value.get(..3) {
+            Some(components) => Vector3::try_from(components),
+            None => Err(ParseVector3Error::InvalidVec { found: value.len() }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let values = [1.0, 2.0, 3.0];
+        assert_eq!(
+            Vector3::try_from(&values[..]).unwrap(),
+            Vector3::new(1.0, 2.0, 3.0)
+        );
+        for found in [0, 2, 4] {
+            let values = vec![7; found];
+            let error = Vector3::try_from(values.as_slice()).unwrap_err();
+            assert!(matches!(error, ParseVector3Error::InvalidVec { found: f } if f == found));
+        }
+        let error = Vector3::<i32>::try_from(&[1, 2][..]).unwrap_err();
+        assert_eq!(error.to_string(), "expected 3 components, found 2");
+    }
+
+    #[test]
+    fn from_vec() {
+        assert_eq!(
+            Vector3::try_from(vec![1, 2, 3, 4]).unwrap(),
+            Vector3::new(1, 2, 3)
+        );
+        let error = Vector3::<i32>::try_from(vec![1]).unwrap_err();
+        assert!(matches!(error, ParseVector3Error::InvalidVec { found: 1 }));
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec3> for Vector3<f32> {
+    fn from(value: glam::Vec3) -> Self {
+        Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vector3<f32>> for glam::Vec3 {
+    fn from(value: Vector3<f32>) -> Self {
+        glam::Vec3::new(value.x, value.y, value.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec3> for Vector3<f64> {
+    fn from(value: glam::DVec3) -> Self {
+        Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vector3<f64>> for glam::DVec3 {
+    fn from(value: Vector3<f64>) -> Self {
+        glam::DVec3::new(value.x, value.y, value.z)
+    }
+}
+
+#[cfg(all(test, feature = "glam"))]
+mod glam_tests {
+    use super::*;
+
+    #[test]
+    fn glam_vec3_round_trip() {
+        let original: Vector3<f32> = Vector3::new(1.5, -2.0, 3.25);
+        let converted = glam::Vec3::from(original);
+        assert_eq!(converted, glam::Vec3::new(1.5, -2.0, 3.25));
+        assert_eq!(Vector3::from(converted), original);
+    }
 
-        Ok(Vector3::new(*x, *y, *z))
+    #[test]
+    fn glam_dvec3_round_trip() {
+        let original: Vector3<f64> = Vector3::new(0.1, 0.2, -0.3);
+        let converted = glam::DVec3::from(original);
+        assert_eq!(converted, glam::DVec3::new(0.1, 0.2, -0.3));
+        assert_eq!(Vector3::from(converted), original);
+    }
+}
+
+/// Converts from `nalgebra::Vector3<T>` for every supported coordinate type
+/// (`f32`, `f64`, and all signed and unsigned primitive integers).
+#[cfg(feature = "nalgebra")]
+impl<T: Vector3Coordinate + nalgebra::Scalar> From<nalgebra::Vector3<T>> for Vector3<T> {
+    fn from(value: nalgebra::Vector3<T>) -> Self {
+        Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+/// Converts into `nalgebra::Vector3<T>` for every supported coordinate type
+/// (`f32`, `f64`, and all signed and unsigned primitive integers).
+#[cfg(feature = "nalgebra")]
+impl<T: Vector3Coordinate + nalgebra::Scalar> From<Vector3<T>> for nalgebra::Vector3<T> {
+    fn from(value: Vector3<T>) -> Self {
+        nalgebra::Vector3::new(value.x, value.y, value.z)
+    }
+}
+
+#[cfg(all(test, feature = "nalgebra"))]
+mod nalgebra_tests {
+    use super::*;
+
+    #[test]
+    fn nalgebra_float_round_trip() {
+        let original: Vector3<f64> = Vector3::new(1.5, -2.0, 3.25);
+        let converted = nalgebra::Vector3::from(original);
+        assert_eq!(converted, nalgebra::Vector3::new(1.5, -2.0, 3.25));
+        assert_eq!(Vector3::from(converted), original);
+    }
+
+    #[test]
+    fn nalgebra_integer_round_trip() {
+        let original: Vector3<i16> = Vector3::new(-7, 0, 42);
+        let converted = nalgebra::Vector3::from(original);
+        assert_eq!(converted, nalgebra::Vector3::new(-7, 0, 42));
+        assert_eq!(Vector3::from(converted), original);
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T: Vector3Coordinate> From<mint::Vector3<T>> for Vector3<T> {
+    fn from(value: mint::Vector3<T>) -> Self {
+        Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T: Vector3Coordinate> From<Vector3<T>> for mint::Vector3<T> {
+    fn from(value: Vector3<T>) -> Self {
+        mint::Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T: Vector3Coordinate> mint::IntoMint for Vector3<T> {
+    type MintType = mint::Vector3<T>;
+}
+
+#[cfg(all(test, feature = "mint"))]
+mod mint_tests {
+    use super::*;
+
+    #[test]
+    fn mint_round_trip() {
+        let original: Vector3<f32> = Vector3::new(1.5, -2.0, 3.25);
+        let converted = mint::Vector3::from(original);
+        assert_eq!(
+            converted,
+            mint::Vector3 {
+                x: 1.5,
+                y: -2.0,
+                z: 3.25
+            }
+        );
+        assert_eq!(Vector3::from(converted), original);
+    }
+
+    #[test]
+    fn mint_into_mint() {
+        fn to_mint<V: mint::IntoMint>(value: V) -> V::MintType {
+            value.into()
+        }
+        let converted = to_mint(Vector3::new(1u8, 2, 3));
+        assert_eq!(converted, mint::Vector3 { x: 1, y: 2, z: 3 });
     }
 }
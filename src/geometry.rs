@@ -0,0 +1,861 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::{consts, Vector3};
+use std::cmp::Ordering;
+
+impl Vector3<f64> {
+    /// Returns a vector orthogonal to this one, not normalized.
+    ///
+    /// It is chosen stably by crossing with whichever axis is least aligned with
+    /// this vector. The zero vector yields the zero vector.
+    pub fn perpendicular(&self) -> Vector3<f64> {
+        let (x, y, z) = (self.x.abs(), self.y.abs(), self.z.abs());
+        let axis = if x <= y && x <= z {
+            consts::X_AXIS
+        } else if y <= z {
+            consts::Y_AXIS
+        } else {
+            consts::Z_AXIS
+        };
+        self.cross(&axis)
+    }
+
+    /// Builds an orthonormal Frenet frame from the velocity (`tangent`) and
+    /// `acceleration` of a curve, returned as `(tangent, normal, binormal)`.
+    ///
+    /// The normal is the normalized component of `acceleration` perpendicular to
+    /// `tangent`. On straight segments, where that component vanishes, an arbitrary
+    /// vector perpendicular to `tangent` is used instead.
+    ///
+    /// `tangent` must be non-zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vec3_rs::Vector3;
+    ///
+    /// let velocity = Vector3::new(1.0, 0.0, 0.0);
+    /// let acceleration = Vector3::new(0.0, 1.0, 0.0);
+    /// let (t, n, b) = Vector3::frenet_frame(&velocity, &acceleration);
+    /// assert_eq!(b, t.cross(&n));
+    /// ```
+    pub fn frenet_frame(
+        tangent: &Vector3<f64>,
+        acceleration: &Vector3<f64>,
+    ) -> (Vector3<f64>, Vector3<f64>, Vector3<f64>) {
+        let tangent = *tangent / tangent.magnitude();
+        let mut normal = *acceleration - tangent * acceleration.dot(&tangent);
+        let normal_magnitude = normal.magnitude();
+        if normal_magnitude <= 1e-12 * acceleration.magnitude() {
+            normal = tangent.perpendicular();
+            normal.normalize();
+        } else {
+            normal /= normal_magnitude;
+        }
+        let binormal = tangent.cross(&normal);
+        (tangent, normal, binormal)
+    }
+
+    /// Flattens every point in `points` onto the plane through `plane_point` with
+    /// normal `plane_normal`, in place.
+    ///
+    /// The normal does not need to be unit length; it is normalized once and shared
+    /// across all points. A zero normal defines no plane, and `points` is left
+    /// unchanged.
+    pub fn project_points_to_plane(
+        points: &mut [Vector3<f64>],
+        plane_point: &Vector3<f64>,
+        plane_normal: &Vector3<f64>,
+    ) {
+        let length = plane_normal.magnitude();
+        if length == 0.0 {
+            return;
+        }
+        let normal = *plane_normal / length;
+        for point in points.iter_mut() {
+            let distance = (*point - *plane_point).dot(&normal);
+            *point -= normal * distance;
+        }
+    }
+
+    /// Constrains this direction to lie within the cone around `cone_axis` with
+    /// half-angle `max_angle_rad`.
+    ///
+    /// Directions already inside the cone are returned unchanged. Directions outside
+    /// are rotated along the shortest arc onto the cone surface, keeping their
+    /// magnitude. A direction exactly opposite the axis is rotated towards an
+    /// arbitrary side of the cone.
+    pub fn clamp_to_cone(&self, cone_axis: &Self, max_angle_rad: f64) -> Vector3<f64> {
+        let magnitude = self.magnitude();
+        if magnitude == 0.0 {
+            return *self;
+        }
+        let axis = *cone_axis / cone_axis.magnitude();
+        let direction = *self / magnitude;
+        let cos_angle = direction.dot(&axis).clamp(-1.0, 1.0);
+        if cos_angle.acos() <= max_angle_rad {
+            return *self;
+        }
+
+        let mut side = direction - axis * cos_angle;
+        if side.magnitude() <= 1e-12 {
+            side = axis.perpendicular();
+        }
+        side.normalize();
+        (axis * max_angle_rad.cos() + side * max_angle_rad.sin()) * magnitude
+    }
+
+    /// Computes the distance from this point to the infinite line through `a` and `b`.
+    ///
+    /// When `a == b` the line is degenerate and the distance to `a` is returned.
+    pub fn distance_to_line(&self, a: &Self, b: &Self) -> f64 {
+        let direction = *b - *a;
+        let length = direction.magnitude();
+        if length == 0.0 {
+            return (*self - *a).magnitude();
+        }
+        direction.cross(&(*self - *a)).magnitude() / length
+    }
+
+    /// Computes the distance from this point to the segment between `a` and `b`.
+    ///
+    /// When `a == b` the segment is degenerate and the distance to `a` is returned.
+    pub fn distance_to_segment(&self, a: &Self, b: &Self) -> f64 {
+        (*self - self.closest_point_on_segment(a, b)).magnitude()
+    }
+
+    /// Finds the point on the segment between `a` and `b` nearest to this point.
+    ///
+    /// When `a == b` the segment is degenerate and `a` is returned.
+    pub fn closest_point_on_segment(&self, a: &Self, b: &Self) -> Vector3<f64> {
+        let direction = *b - *a;
+        let length_squared = direction.dot(&direction);
+        if length_squared == 0.0 {
+            return *a;
+        }
+        let t = ((*self - *a).dot(&direction) / length_squared).clamp(0.0, 1.0);
+        *a + direction * t
+    }
+
+    /// Reflects this vector off a surface with the given unit `normal`.
+    pub fn reflect(&self, normal: &Self) -> Vector3<f64> {
+        *self - *normal * (2.0 * self.dot(normal))
+    }
+
+    /// Mirrors this point across the plane through `plane_point` with the given
+    /// `plane_normal`, which need not be unit length.
+    ///
+    /// A zero normal defines no plane, and the point is returned unchanged.
+    pub fn reflect_across_plane(&self, plane_point: &Self, plane_normal: &Self) -> Vector3<f64> {
+        let offset = (*self - *plane_point).project_onto(plane_normal);
+        *self - offset * 2.0
+    }
+
+    /// Blends between passing straight through (`blend = 0.0`) and fully reflecting
+    /// off the surface with unit `normal` (`blend = 1.0`) by linear interpolation.
+    pub fn reflect_blended(&self, normal: &Self, blend: f64) -> Vector3<f64> {
+        self.lerp(&self.reflect(normal), blend)
+    }
+
+    /// Refracts this unit incident direction through a surface using Snell's law,
+    /// where `eta` is the ratio of the incident to the transmitted index of refraction.
+    ///
+    /// `normal` must be unit length and face against the incident direction, i.e.
+    /// `self.dot(normal) <= 0.0`; flip it when the ray leaves the medium.
+    ///
+    /// Returns `None` on total internal reflection.
+    pub fn refract(&self, normal: &Self, eta: f64) -> Option<Vector3<f64>> {
+        let cos_incident = -self.dot(normal);
+        let k = 1.0 - eta * eta * (1.0 - cos_incident * cos_incident);
+        if k < 0.0 {
+            return None;
+        }
+        Some(*self * eta + *normal * (eta * cos_incident - k.sqrt()))
+    }
+
+    /// Computes the area of the triangle `(a, b, c)` as half the magnitude of
+    /// `(b - a) × (c - a)`.
+    pub fn triangle_area(a: &Self, b: &Self, c: &Self) -> f64 {
+        (*b - *a).cross(&(*c - *a)).magnitude() / 2.0
+    }
+
+    /// Computes the unit normal of the triangle `(a, b, c)` as the normalized
+    /// `(b - a) × (c - a)`, so its direction follows the winding order.
+    ///
+    /// Degenerate (zero-area) triangles yield the zero vector.
+    pub fn triangle_normal(a: &Self, b: &Self, c: &Self) -> Vector3<f64> {
+        let mut normal = (*b - *a).cross(&(*c - *a));
+        if normal.magnitude() == 0.0 {
+            return consts::VECTOR3_ZERO;
+        }
+        *normal.normalize()
+    }
+
+    /// Determines the turn direction of `a → b → c` as seen from the side that
+    /// `normal` points towards, using the sign of `((b - a) × (c - a)) · normal`.
+    ///
+    /// Returns `Greater` for counter-clockwise, `Less` for clockwise and `Equal` for
+    /// collinear points (or a `NaN` triple product).
+    pub fn orientation(a: &Self, b: &Self, c: &Self, normal: &Self) -> Ordering {
+        let triple = (*b - *a).cross(&(*c - *a)).dot(normal);
+        triple.partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+    }
+
+    /// Computes the barycentric coordinates `(u, v, w)` of `p` with respect to the
+    /// triangle `(a, b, c)`, such that `u * a + v * b + w * c == p` for points in the
+    /// triangle's plane. Points off the plane are projected onto it first.
+    ///
+    /// Degenerate (zero-area) triangles have no well-defined coordinates and yield
+    /// `(NaN, NaN, NaN)`.
+    pub fn barycentric(p: &Self, a: &Self, b: &Self, c: &Self) -> (f64, f64, f64) {
+        let edge_ab = *b - *a;
+        let edge_ac = *c - *a;
+        let to_p = *p - *a;
+        let d00 = edge_ab.dot(&edge_ab);
+        let d01 = edge_ab.dot(&edge_ac);
+        let d11 = edge_ac.dot(&edge_ac);
+        let d20 = to_p.dot(&edge_ab);
+        let d21 = to_p.dot(&edge_ac);
+        let denominator = d00 * d11 - d01 * d01;
+        if denominator == 0.0 {
+            return (f64::NAN, f64::NAN, f64::NAN);
+        }
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+        (1.0 - v - w, v, w)
+    }
+
+    /// Checks whether three points lie on a common line, within `epsilon` on the
+    /// magnitude of `(b - a) × (c - a)`.
+    ///
+    /// Coincident points are always considered collinear.
+    pub fn are_collinear(a: &Self, b: &Self, c: &Self, epsilon: f64) -> bool {
+        (*b - *a).cross(&(*c - *a)).magnitude() <= epsilon
+    }
+
+    /// Checks whether four points lie on a common plane, within `epsilon` on the
+    /// absolute scalar triple product of the edges from `a` to the other points.
+    pub fn are_coplanar(a: &Self, b: &Self, c: &Self, d: &Self, epsilon: f64) -> bool {
+        let triple_product = (*b - *a).dot(&(*c - *a).cross(&(*d - *a)));
+        triple_product.abs() <= epsilon
+    }
+
+    /// Blends weighted points into `Σ wᵢ·pᵢ / Σ wᵢ`, dividing by the total weight so
+    /// the weights need not sum to one.
+    ///
+    /// Returns `None` for an empty slice or when the weights sum to zero.
+    pub fn weighted_average(points: &[(Vector3<f64>, f64)]) -> Option<Vector3<f64>> {
+        let mut sum = consts::VECTOR3_ZERO;
+        let mut total_weight = 0.0;
+        for &(point, weight) in points {
+            sum += point * weight;
+            total_weight += weight;
+        }
+        if total_weight == 0.0 {
+            return None;
+        }
+        Some(sum / total_weight)
+    }
+
+    /// Computes the total length of the polyline through `points`, summing the
+    /// lengths of consecutive segments. Paths with fewer than two points have length
+    /// `0.0`.
+    pub fn path_length(points: &[Vector3<f64>]) -> f64 {
+        points
+            .windows(2)
+            .map(|segment| (segment[1] - segment[0]).magnitude())
+            .sum()
+    }
+
+    /// Samples the polyline through `points` at arc length `distance` from the first
+    /// point, clamped to the ends of the path.
+    ///
+    /// Returns `None` for paths with fewer than two points.
+    pub fn interpolate_along_path(points: &[Vector3<f64>], distance: f64) -> Option<Vector3<f64>> {
+        if points.len() < 2 {
+            return None;
+        }
+        if distance <= 0.0 {
+            return Some(points[0]);
+        }
+        let mut remaining = distance;
+        for segment in points.windows(2) {
+            let offset = segment[1] - segment[0];
+            let length = offset.magnitude();
+            if remaining <= length {
+                return Some(segment[0] + offset * (remaining / length));
+            }
+            remaining -= length;
+        }
+        points.last().copied()
+    }
+
+    /// Translates and uniformly scales `points` in place so that their bounding box
+    /// starts at the origin and its longest axis spans `[0, 1]`, preserving the
+    /// aspect ratio.
+    ///
+    /// A degenerate set whose bounding box has zero extent (such as a single point)
+    /// is moved to the origin without scaling.
+    pub fn normalize_to_unit_cube(points: &mut [Vector3<f64>]) {
+        let Some((min, max)) = Vector3::aabb(points.iter().copied()) else {
+            return;
+        };
+        let extent = max - min;
+        let longest = extent.x.max(extent.y).max(extent.z);
+        for point in points.iter_mut() {
+            *point -= min;
+            if longest > 0.0 {
+                *point /= longest;
+            }
+        }
+    }
+
+    /// Estimates the dominant principal axis of a point set as a unit vector, using
+    /// `iterations` rounds of power iteration on the covariance matrix.
+    ///
+    /// The sign of the returned axis is arbitrary. Returns `None` for fewer than two
+    /// points or when all points coincide.
+    pub fn principal_axis(points: &[Vector3<f64>], iterations: usize) -> Option<Vector3<f64>> {
+        if points.len() < 2 {
+            return None;
+        }
+        let mut centroid = consts::VECTOR3_ZERO;
+        centroid.accumulate(points);
+        centroid /= points.len() as f64;
+
+        let mut covariance = [[0.0; 3]; 3];
+        for point in points {
+            let offset = *point - centroid;
+            let outer = offset.outer_product(&offset);
+            for (row, outer_row) in covariance.iter_mut().zip(outer) {
+                for (value, outer_value) in row.iter_mut().zip(outer_row) {
+                    *value += outer_value;
+                }
+            }
+        }
+        let apply = |v: &Vector3<f64>| {
+            let [r0, r1, r2] = covariance.map(Vector3::from);
+            Vector3::new(r0.dot(v), r1.dot(v), r2.dot(v))
+        };
+
+        let power_iteration = |start: Vector3<f64>| {
+            let magnitude = start.magnitude();
+            if magnitude == 0.0 {
+                return None;
+            }
+            let mut axis = start / magnitude;
+            for _ in 0..iterations {
+                let next = apply(&axis);
+                let magnitude = next.magnitude();
+                if magnitude == 0.0 {
+                    break;
+                }
+                axis = next / magnitude;
+            }
+            Some(axis)
+        };
+
+        // A single start can be orthogonal, or nearly so, to the dominant
+        // eigenvector and converge to a smaller one. The columns span the range of
+        // the covariance, so at least one of them has a component along it; iterate
+        // from each and keep the result with the largest Rayleigh quotient.
+        [consts::X_AXIS, consts::Y_AXIS, consts::Z_AXIS]
+            .into_iter()
+            .filter_map(|axis| power_iteration(apply(&axis)))
+            .max_by(|a, b| a.dot(&apply(a)).total_cmp(&b.dot(&apply(b))))
+    }
+
+    /// Orthonormalizes three vectors in order using the Gram-Schmidt process.
+    ///
+    /// The first output is `a` normalized, the second is the part of `b` orthogonal
+    /// to it, and the third is the part of `c` orthogonal to both. When an input is
+    /// (nearly) linearly dependent on the ones before it, nothing of it remains and
+    /// the matching output is the zero vector.
+    pub fn gram_schmidt(a: &Self, b: &Self, c: &Self) -> (Self, Self, Self) {
+        let orthonormalize = |v: &Self, basis: &[Self]| {
+            let mut residual = *v;
+            for u in basis {
+                residual -= *u * residual.dot(u);
+            }
+            let magnitude = residual.magnitude();
+            if magnitude <= 1e-12 * v.magnitude() {
+                consts::VECTOR3_ZERO
+            } else {
+                residual / magnitude
+            }
+        };
+        let first = orthonormalize(a, &[]);
+        let second = orthonormalize(b, &[first]);
+        let third = orthonormalize(c, &[first, second]);
+        (first, second, third)
+    }
+
+    /// Builds an orthonormal `(right, up, forward)` basis looking along `forward`,
+    /// re-orthogonalizing the `up` hint against it.
+    ///
+    /// The basis follows the crate's left-handed, Y-up convention (see
+    /// [`consts::FORWARD`]): `right × up == forward`, so looking along +Z with +Y up
+    /// gives +X as right. When `up` is parallel to `forward`, an arbitrary
+    /// perpendicular up hint is used instead. `forward` must be non-zero.
+    pub fn look_rotation(forward: &Self, up: &Self) -> (Self, Self, Self) {
+        let forward = *forward / forward.magnitude();
+        let mut right = up.cross(&forward);
+        if right.magnitude() <= 1e-12 * up.magnitude() {
+            right = forward.perpendicular().cross(&forward);
+        }
+        right.normalize();
+        let up = forward.cross(&right);
+        (right, up, forward)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_orthonormal(a: &Vector3<f64>, b: &Vector3<f64>, c: &Vector3<f64>) {
+        for v in [a, b, c] {
+            assert!((v.magnitude() - 1.0).abs() < 1e-12);
+        }
+        assert!(a.dot(b).abs() < 1e-12);
+        assert!(b.dot(c).abs() < 1e-12);
+        assert!(c.dot(a).abs() < 1e-12);
+    }
+
+    #[test]
+    fn perpendicular_vectors() {
+        for v in [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-4.0, 0.0, 0.5),
+            Vector3::new(0.0, 0.0, 7.0),
+            Vector3::new(1e-8, 5.0, -5.0),
+        ] {
+            let perpendicular = v.perpendicular();
+            assert!(perpendicular.magnitude() > 0.0);
+            assert_eq!(perpendicular.dot(&v), 0.0);
+        }
+        assert_eq!(consts::VECTOR3_ZERO.perpendicular(), consts::VECTOR3_ZERO);
+    }
+
+    #[test]
+    fn frenet_frame_curved() {
+        let velocity = Vector3::new(2.0, 0.0, 0.0);
+        let acceleration = Vector3::new(1.0, 3.0, 0.0);
+        let (t, n, b) = Vector3::frenet_frame(&velocity, &acceleration);
+        assert_eq!(t, consts::X_AXIS);
+        assert_eq!(n, consts::Y_AXIS);
+        assert_eq!(b, consts::Z_AXIS);
+    }
+
+    #[test]
+    fn frenet_frame_straight() {
+        let velocity = Vector3::new(1.0, 1.0, 1.0);
+        let acceleration = Vector3::new(2.0, 2.0, 2.0);
+        let (t, n, b) = Vector3::frenet_frame(&velocity, &acceleration);
+        assert_orthonormal(&t, &n, &b);
+
+        let (t, n, b) = Vector3::frenet_frame(&velocity, &consts::VECTOR3_ZERO);
+        assert_orthonormal(&t, &n, &b);
+    }
+
+    #[test]
+    fn project_points_to_xy_plane() {
+        let mut points = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-4.0, 0.5, -2.0),
+            Vector3::new(0.0, 0.0, 0.0),
+        ];
+        let normal = Vector3::new(0.0, 0.0, 5.0);
+        Vector3::project_points_to_plane(&mut points, &consts::VECTOR3_ZERO, &normal);
+        assert_eq!(
+            points,
+            [
+                Vector3::new(1.0, 2.0, 0.0),
+                Vector3::new(-4.0, 0.5, 0.0),
+                Vector3::new(0.0, 0.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn project_points_zero_normal() {
+        let original = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(-4.0, 0.5, -2.0)];
+        let mut points = original;
+        Vector3::project_points_to_plane(&mut points, &consts::X_AXIS, &consts::VECTOR3_ZERO);
+        assert_eq!(points, original);
+    }
+
+    #[test]
+    fn clamp_to_cone_inside() {
+        let direction = Vector3::new(0.1, 0.0, 1.0);
+        let clamped = direction.clamp_to_cone(&consts::Z_AXIS, std::f64::consts::FRAC_PI_4);
+        assert_eq!(clamped, direction);
+    }
+
+    #[test]
+    fn clamp_to_cone_outside() {
+        let max_angle = std::f64::consts::FRAC_PI_4;
+        let direction = Vector3::new(2.0, 0.0, 0.0);
+        let clamped = direction.clamp_to_cone(&consts::Z_AXIS, max_angle);
+        let expected = Vector3::new(max_angle.sin(), 0.0, max_angle.cos()) * 2.0;
+        assert!(clamped.fuzzy_equal(&expected, 1e-12));
+
+        let opposite = Vector3::new(0.0, 0.0, -1.0);
+        let clamped = opposite.clamp_to_cone(&consts::Z_AXIS, max_angle);
+        assert!((clamped.angle(&consts::Z_AXIS) - max_angle).abs() < 1e-12);
+        assert!((clamped.magnitude() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line_and_segment_distance() {
+        let a = Vector3::new(0.0, 0.0, 0.0);
+        let b = Vector3::new(4.0, 0.0, 0.0);
+
+        let beside_middle = Vector3::new(2.0, 3.0, 0.0);
+        assert_eq!(beside_middle.distance_to_line(&a, &b), 3.0);
+        assert_eq!(beside_middle.distance_to_segment(&a, &b), 3.0);
+
+        let past_end = Vector3::new(7.0, 4.0, 0.0);
+        assert_eq!(past_end.distance_to_line(&a, &b), 4.0);
+        assert_eq!(past_end.distance_to_segment(&a, &b), 5.0);
+
+        let point = Vector3::new(3.0, 4.0, 0.0);
+        assert_eq!(point.distance_to_line(&a, &a), 5.0);
+        assert_eq!(point.distance_to_segment(&a, &a), 5.0);
+    }
+
+    #[test]
+    fn closest_point_segment() {
+        let a = Vector3::new(1.0, 1.0, 0.0);
+        let b = Vector3::new(1.0, 5.0, 0.0);
+        let interior = Vector3::new(-2.0, 3.0, 4.0);
+        assert_eq!(
+            interior.closest_point_on_segment(&a, &b),
+            Vector3::new(1.0, 3.0, 0.0)
+        );
+        let before_start = Vector3::new(0.0, -1.0, 0.0);
+        assert_eq!(before_start.closest_point_on_segment(&a, &b), a);
+        let past_end = Vector3::new(3.0, 9.0, 1.0);
+        assert_eq!(past_end.closest_point_on_segment(&a, &b), b);
+        assert_eq!(interior.closest_point_on_segment(&a, &a), a);
+    }
+
+    #[test]
+    fn mirror_across_plane() {
+        let point = Vector3::new(1.0, 2.0, 3.0);
+        let origin = consts::VECTOR3_ZERO;
+        assert_eq!(
+            point.reflect_across_plane(&origin, &consts::Z_AXIS),
+            Vector3::new(1.0, 2.0, -3.0)
+        );
+        assert_eq!(
+            point.reflect_across_plane(&Vector3::new(5.0, 5.0, 1.0), &(consts::Z_AXIS * 4.0)),
+            Vector3::new(1.0, 2.0, -1.0)
+        );
+
+        // The plane x + y = 2, tilted 45 degrees between the X and Y axes.
+        let mirrored =
+            point.reflect_across_plane(&Vector3::new(1.0, 1.0, 0.0), &Vector3::new(1.0, 1.0, 0.0));
+        assert!(mirrored.fuzzy_equal(&Vector3::new(0.0, 1.0, 3.0), 1e-12));
+        assert!(mirrored
+            .reflect_across_plane(&Vector3::new(2.0, 0.0, 0.0), &Vector3::new(-1.0, -1.0, 0.0))
+            .fuzzy_equal(&point, 1e-12));
+
+        assert_eq!(point.reflect_across_plane(&origin, &origin), point);
+    }
+
+    #[test]
+    fn reflection_blending() {
+        let incident = Vector3::new(1.0, -1.0, 0.0);
+        assert_eq!(
+            incident.reflect(&consts::Y_AXIS),
+            Vector3::new(1.0, 1.0, 0.0)
+        );
+        assert_eq!(incident.reflect_blended(&consts::Y_AXIS, 0.0), incident);
+        assert_eq!(
+            incident.reflect_blended(&consts::Y_AXIS, 0.5),
+            Vector3::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            incident.reflect_blended(&consts::Y_AXIS, 1.0),
+            Vector3::new(1.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn refraction_straight_through() {
+        let mut incident: Vector3<f64> = Vector3::new(1.0, -1.0, 0.0);
+        incident.normalize();
+        let refracted = incident.refract(&consts::Y_AXIS, 1.0).unwrap();
+        assert!(refracted.fuzzy_equal(&incident, 1e-12));
+    }
+
+    #[test]
+    fn refraction_total_internal_reflection() {
+        let mut incident: Vector3<f64> = Vector3::new(1.0, -0.2, 0.0);
+        incident.normalize();
+        assert_eq!(incident.refract(&consts::Y_AXIS, 1.5), None);
+    }
+
+    #[test]
+    fn triangle_area_and_normal() {
+        let a = Vector3::new(0.0, 0.0, 0.0);
+        let b = Vector3::new(1.0, 0.0, 0.0);
+        let c = Vector3::new(0.0, 1.0, 0.0);
+        assert_eq!(Vector3::triangle_area(&a, &b, &c), 0.5);
+        assert_eq!(Vector3::triangle_normal(&a, &b, &c), consts::Z_AXIS);
+        assert_eq!(Vector3::triangle_normal(&a, &c, &b), consts::NEG_Z_AXIS);
+
+        let scaled = Vector3::triangle_area(&(a * 4.0), &(b * 4.0), &(c * 4.0));
+        assert_eq!(scaled, 8.0);
+    }
+
+    #[test]
+    fn triangle_degenerate() {
+        let a = Vector3::new(1.0, 1.0, 1.0);
+        let b = Vector3::new(2.0, 2.0, 2.0);
+        let c = Vector3::new(3.0, 3.0, 3.0);
+        assert_eq!(Vector3::triangle_area(&a, &b, &c), 0.0);
+        assert_eq!(Vector3::triangle_normal(&a, &b, &c), consts::VECTOR3_ZERO);
+        assert_eq!(Vector3::triangle_normal(&a, &a, &a), consts::VECTOR3_ZERO);
+    }
+
+    #[test]
+    fn winding_orientation() {
+        let a = Vector3::new(0.0, 0.0, 0.0);
+        let b = Vector3::new(1.0, 0.0, 0.0);
+        let c = Vector3::new(1.0, 1.0, 0.0);
+        let up = consts::Z_AXIS;
+        assert_eq!(Vector3::orientation(&a, &b, &c, &up), Ordering::Greater);
+        assert_eq!(Vector3::orientation(&a, &c, &b, &up), Ordering::Less);
+        assert_eq!(
+            Vector3::orientation(&a, &b, &c, &consts::NEG_Z_AXIS),
+            Ordering::Less
+        );
+        let d = Vector3::new(2.0, 0.0, 0.0);
+        assert_eq!(Vector3::orientation(&a, &b, &d, &up), Ordering::Equal);
+    }
+
+    #[test]
+    fn barycentric_coordinates() {
+        let a = Vector3::new(0.0, 0.0, 0.0);
+        let b = Vector3::new(3.0, 0.0, 0.0);
+        let c = Vector3::new(0.0, 3.0, 0.0);
+        assert_eq!(Vector3::barycentric(&a, &a, &b, &c), (1.0, 0.0, 0.0));
+        assert_eq!(Vector3::barycentric(&b, &a, &b, &c), (0.0, 1.0, 0.0));
+        assert_eq!(Vector3::barycentric(&c, &a, &b, &c), (0.0, 0.0, 1.0));
+
+        let centroid = (a + b + c) / 3.0;
+        let (u, v, w) = Vector3::barycentric(&centroid, &a, &b, &c);
+        for weight in [u, v, w] {
+            assert!((weight - 1.0 / 3.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn barycentric_degenerate() {
+        let a = Vector3::new(0.0, 0.0, 0.0);
+        let b = Vector3::new(1.0, 1.0, 1.0);
+        let c = Vector3::new(2.0, 2.0, 2.0);
+        let (u, v, w) = Vector3::barycentric(&b, &a, &b, &c);
+        assert!(u.is_nan() && v.is_nan() && w.is_nan());
+    }
+
+    #[test]
+    fn collinearity() {
+        let a = Vector3::new(0.0, 0.0, 0.0);
+        let b = Vector3::new(1.0, 2.0, 3.0);
+        let c = Vector3::new(-2.0, -4.0, -6.0);
+        assert!(Vector3::are_collinear(&a, &b, &c, 1e-12));
+
+        let off_line = Vector3::new(1.0, 2.0, 3.5);
+        assert!(!Vector3::are_collinear(&a, &off_line, &c, 1e-12));
+
+        assert!(Vector3::are_collinear(&b, &b, &b, 0.0));
+        assert!(Vector3::are_collinear(&a, &a, &off_line, 0.0));
+    }
+
+    #[test]
+    fn coplanarity() {
+        let a = Vector3::new(0.0, 0.0, 1.0);
+        let b = Vector3::new(1.0, 0.0, 1.0);
+        let c = Vector3::new(0.0, 1.0, 1.0);
+        let d = Vector3::new(5.0, -3.0, 1.0);
+        assert!(Vector3::are_coplanar(&a, &b, &c, &d, 1e-12));
+
+        let lifted = Vector3::new(5.0, -3.0, 1.5);
+        assert!(!Vector3::are_coplanar(&a, &b, &c, &lifted, 1e-12));
+    }
+
+    #[test]
+    fn weighted_average_points() {
+        let a = Vector3::new(0.0, 0.0, 0.0);
+        let b = Vector3::new(4.0, 8.0, -4.0);
+        assert_eq!(
+            Vector3::weighted_average(&[(a, 3.0), (b, 1.0)]),
+            Some(Vector3::new(1.0, 2.0, -1.0))
+        );
+
+        let c = Vector3::new(0.0, 0.0, 6.0);
+        let average = Vector3::weighted_average(&[(a, 0.5), (b, 0.5), (c, 1.0)]).unwrap();
+        assert!(average.fuzzy_equal(&Vector3::new(1.0, 2.0, 2.0), 1e-12));
+    }
+
+    #[test]
+    fn weighted_average_degenerate() {
+        assert_eq!(Vector3::weighted_average(&[]), None);
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(Vector3::weighted_average(&[(a, 1.0), (a, -1.0)]), None);
+    }
+
+    #[test]
+    fn polyline_length() {
+        let straight = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 2.0),
+            Vector3::new(0.0, 0.0, 5.0),
+        ];
+        assert_eq!(Vector3::path_length(&straight), 5.0);
+        let l_shape = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(3.0, 0.0, 0.0),
+            Vector3::new(3.0, 4.0, 0.0),
+        ];
+        assert_eq!(Vector3::path_length(&l_shape), 7.0);
+        assert_eq!(Vector3::path_length(&l_shape[..1]), 0.0);
+        assert_eq!(Vector3::path_length(&[]), 0.0);
+    }
+
+    #[test]
+    fn path_interpolation() {
+        let path = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(3.0, 0.0, 0.0),
+            Vector3::new(3.0, 0.0, 0.0),
+            Vector3::new(3.0, 4.0, 0.0),
+        ];
+        let at = |distance| Vector3::interpolate_along_path(&path, distance).unwrap();
+        assert_eq!(at(0.0), path[0]);
+        assert_eq!(at(1.5), Vector3::new(1.5, 0.0, 0.0));
+        assert_eq!(at(3.0), Vector3::new(3.0, 0.0, 0.0));
+        assert_eq!(at(5.0), Vector3::new(3.0, 2.0, 0.0));
+        assert_eq!(at(7.0), path[3]);
+        assert_eq!(at(-1.0), path[0]);
+        assert_eq!(at(100.0), path[3]);
+    }
+
+    #[test]
+    fn path_interpolation_degenerate() {
+        assert_eq!(Vector3::interpolate_along_path(&[], 1.0), None);
+        let point = [Vector3::new(1.0, 2.0, 3.0)];
+        assert_eq!(Vector3::interpolate_along_path(&point, 0.0), None);
+    }
+
+    #[test]
+    fn unit_cube_normalization() {
+        let mut points = [
+            Vector3::new(-2.0, 1.0, 3.0),
+            Vector3::new(2.0, 3.0, 4.0),
+            Vector3::new(0.0, 2.0, 3.5),
+        ];
+        Vector3::normalize_to_unit_cube(&mut points);
+        for point in &points {
+            for component in [point.x, point.y, point.z] {
+                assert!((0.0..=1.0).contains(&component));
+            }
+        }
+        assert_eq!(points[0], Vector3::new(0.0, 0.0, 0.0));
+        assert_eq!(points[1], Vector3::new(1.0, 0.5, 0.25));
+        assert_eq!(points[2], Vector3::new(0.5, 0.25, 0.125));
+    }
+
+    #[test]
+    fn unit_cube_normalization_degenerate() {
+        let mut points = [Vector3::new(4.0, -1.0, 2.0); 2];
+        Vector3::normalize_to_unit_cube(&mut points);
+        assert_eq!(points, [consts::VECTOR3_ZERO; 2]);
+
+        let mut empty: [Vector3<f64>; 0] = [];
+        Vector3::normalize_to_unit_cube(&mut empty);
+    }
+
+    #[test]
+    fn principal_axis_elongated() {
+        let mut direction: Vector3<f64> = Vector3::new(1.0, 2.0, -0.5);
+        direction.normalize();
+        let side = direction.perpendicular() * 0.1;
+        let points: Vec<Vector3<f64>> = (-10..=10)
+            .flat_map(|i| {
+                let along = direction * f64::from(i);
+                [along + side, along - side]
+            })
+            .collect();
+        let axis = Vector3::principal_axis(&points, 50).unwrap();
+        assert!((axis.dot(&direction).abs() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn principal_axis_not_largest_column() {
+        // The Z column of the covariance has the largest norm, but the dominant
+        // eigenvector (eigenvalue 4) lies along (1, 1, 0).
+        let points = [
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(-1.0, -1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.4),
+            Vector3::new(0.0, 0.0, -1.4),
+        ];
+        let axis = Vector3::principal_axis(&points, 50).unwrap();
+        let expected = Vector3::new(1.0, 1.0, 0.0) / 2f64.sqrt();
+        assert!(axis.dot(&expected).abs() > 1.0 - 1e-9);
+    }
+
+    #[test]
+    fn principal_axis_degenerate() {
+        assert_eq!(Vector3::principal_axis(&[consts::X_AXIS], 10), None);
+        assert_eq!(Vector3::principal_axis(&[consts::X_AXIS; 4], 10), None);
+    }
+
+    #[test]
+    fn gram_schmidt_orthonormal() {
+        let a = Vector3::new(1.0, 1.0, 0.0);
+        let b = Vector3::new(1.0, 0.0, 1.0);
+        let c = Vector3::new(0.0, 1.0, 1.0);
+        let (u1, u2, u3) = Vector3::gram_schmidt(&a, &b, &c);
+        assert_orthonormal(&u1, &u2, &u3);
+        assert!(u1.fuzzy_equal(&(a / a.magnitude()), 1e-12));
+    }
+
+    #[test]
+    fn gram_schmidt_dependent() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(2.0, 4.0, 6.0);
+        let c = consts::Z_AXIS;
+        let (u1, u2, u3) = Vector3::gram_schmidt(&a, &b, &c);
+        assert_eq!(u2, consts::VECTOR3_ZERO);
+        assert!(u1.dot(&u3).abs() < 1e-12);
+        assert!((u3.magnitude() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn look_rotation_basis() {
+        let (right, up, forward) = Vector3::look_rotation(&consts::FORWARD, &consts::UP);
+        assert_eq!(
+            (right, up, forward),
+            (consts::RIGHT, consts::UP, consts::FORWARD)
+        );
+
+        let direction = Vector3::new(1.0, -2.0, 0.5);
+        let (right, up, forward) = Vector3::look_rotation(&direction, &consts::UP);
+        assert_orthonormal(&right, &up, &forward);
+        assert!(forward.fuzzy_equal(&(direction / direction.magnitude()), 1e-12));
+        assert!(right.cross(&up).fuzzy_equal(&forward, 1e-12));
+        assert!(up.dot(&consts::UP) > 0.0);
+    }
+
+    #[test]
+    fn look_rotation_parallel_up() {
+        let (right, up, forward) = Vector3::look_rotation(&consts::UP, &(consts::UP * 3.0));
+        assert_orthonormal(&right, &up, &forward);
+        assert_eq!(forward, consts::UP);
+    }
+}
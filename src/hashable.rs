@@ -0,0 +1,132 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::{Vector3, Vector3Coordinate};
+use std::hash::{Hash, Hasher};
+
+impl Vector3<f64> {
+    /// Returns the raw IEEE 754 bit patterns of the components.
+    pub fn to_bits(&self) -> [u64; 3] {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()]
+    }
+
+    /// Compares two vectors by the bit patterns of their components.
+    ///
+    /// Unlike `==`, a `NaN` component equals a `NaN` with the same bit pattern,
+    /// while `0.0` and `-0.0` are not equal. Useful for caching and dedup.
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl Vector3<f32> {
+    /// Returns the raw IEEE 754 bit patterns of the components.
+    pub fn to_bits(&self) -> [u32; 3] {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()]
+    }
+
+    /// Compares two vectors by the bit patterns of their components.
+    ///
+    /// Unlike `==`, a `NaN` component equals a `NaN` with the same bit pattern,
+    /// while `0.0` and `-0.0` are not equal. Useful for caching and dedup.
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+/// A float vector wrapper that implements `Eq` and `Hash` on the exact bit patterns
+/// of its components, so it can be used as a `HashMap` or `HashSet` key.
+///
+/// Equality is bitwise: `NaN` equals a `NaN` with the same bit pattern, while
+/// `0.0` and `-0.0` are distinct keys.
+#[derive(Debug, Clone, Copy)]
+pub struct HashableVector3<T: Vector3Coordinate>(pub Vector3<T>);
+
+impl PartialEq for HashableVector3<f64> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.structural_eq(&other.0)
+    }
+}
+
+impl Eq for HashableVector3<f64> {}
+
+impl Hash for HashableVector3<f64> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl PartialEq for HashableVector3<f32> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.structural_eq(&other.0)
+    }
+}
+
+impl Eq for HashableVector3<f32> {}
+
+impl Hash for HashableVector3<f32> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl<T: Vector3Coordinate> From<Vector3<T>> for HashableVector3<T> {
+    fn from(value: Vector3<T>) -> Self {
+        HashableVector3(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn bits() {
+        let vec: Vector3<f64> = Vector3::new(1.0, -0.0, 0.0);
+        assert_eq!(vec.to_bits(), [1.0f64.to_bits(), 1 << 63, 0]);
+        let vec: Vector3<f32> = Vector3::new(1.0, -0.0, 0.0);
+        assert_eq!(vec.to_bits(), [1.0f32.to_bits(), 1 << 31, 0]);
+    }
+
+    #[test]
+    fn structural_eq() {
+        let nan: Vector3<f64> = Vector3::new(f64::NAN, 1.0, 2.0);
+        assert_ne!(nan, nan);
+        assert!(nan.structural_eq(&nan));
+        assert!(!nan.structural_eq(&Vector3::new(-f64::NAN, 1.0, 2.0)));
+        assert!(!nan.structural_eq(&Vector3::new(f64::NAN, 1.0, 3.0)));
+        let zero: Vector3<f64> = Vector3::zero();
+        assert!(!zero.structural_eq(&Vector3::new(-0.0, 0.0, 0.0)));
+
+        let nan: Vector3<f32> = Vector3::new(0.0, f32::NAN, 0.0);
+        assert!(nan.structural_eq(&nan));
+        assert!(!nan.structural_eq(&Vector3::zero()));
+    }
+
+    #[test]
+    fn hash_map_keys() {
+        let mut cache = HashMap::new();
+        cache.insert(HashableVector3(Vector3::new(1.0, 2.0, 3.0)), "a");
+        cache.insert(HashableVector3(Vector3::new(0.0, 0.0, 0.0)), "b");
+        cache.insert(HashableVector3(Vector3::new(-0.0, 0.0, 0.0)), "c");
+        cache.insert(HashableVector3(Vector3::new(f64::NAN, 0.0, 0.0)), "d");
+
+        assert_eq!(cache.len(), 4);
+        assert_eq!(cache[&HashableVector3(Vector3::new(1.0, 2.0, 3.0))], "a");
+        assert_eq!(cache[&HashableVector3(Vector3::new(0.0, 0.0, 0.0))], "b");
+        assert_eq!(cache[&HashableVector3(Vector3::new(-0.0, 0.0, 0.0))], "c");
+        assert_eq!(
+            cache[&HashableVector3(Vector3::new(f64::NAN, 0.0, 0.0))],
+            "d"
+        );
+    }
+
+    #[test]
+    fn hash_map_keys_f32() {
+        let mut cache = HashMap::new();
+        let key: Vector3<f32> = Vector3::new(0.5, 0.25, 0.125);
+        cache.insert(HashableVector3::from(key), 1);
+        *cache.entry(key.into()).or_insert(0) += 1;
+        assert_eq!(cache[&HashableVector3(key)], 2);
+    }
+}
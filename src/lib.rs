@@ -1,9 +1,21 @@
 #![deny(unsafe_code, warnings, clippy::all)]
 
+mod angles;
+#[cfg(feature = "approx")]
+mod approx_eq;
+pub mod axis;
+pub mod batch;
 pub mod consts;
 mod convert;
 mod float_lerp;
+mod geometry;
+pub mod hashable;
 mod ops;
+mod physics;
+mod projection;
+pub mod unit;
+pub mod vec2;
+pub mod vec4;
 
 use float_lerp::Lerp;
 use rand::{thread_rng, Rng};
@@ -36,7 +48,11 @@ impl Vector3Coordinate for u64 {}
 impl Vector3Coordinate for u128 {}
 
 /// Represents a vector in 3D space.
+///
+/// The layout is `#[repr(C)]`: the components are stored contiguously in the order
+/// `x`, `y`, `z`, matching a C `T[3]`.
 #[derive(Debug, PartialOrd, PartialEq, Default, Clone, Copy)]
+#[repr(C)]
 pub struct Vector3<T: Vector3Coordinate> {
     x: T,
     y: T,
@@ -55,7 +71,9 @@ where
             z: thread_rng().gen(),
         }
     }
+}
 
+impl<T: Vector3Coordinate + num::Float> Vector3<T> {
     /// Checks if this vector is approximately equal to another vector within a given epsilon.
     ///
     /// # Examples
@@ -89,6 +107,10 @@ where
     }
 
     /// Linearly interpolates between this vector and another vector by a given ratio.
+    ///
+    /// The ratio is not clamped, so values outside `[0, 1]` extrapolate past either
+    /// end. This is the same as [`Vector3::lerp_unclamped`]; use
+    /// [`Vector3::lerp_clamped`] to stay between the two vectors.
     pub fn lerp(&self, target: &Self, alpha: T) -> Self {
         Vector3 {
             x: self.x.lerp(target.x, alpha),
@@ -96,19 +118,330 @@ where
             z: self.z.lerp(target.z, alpha),
         }
     }
+
+    /// Linearly interpolates between this vector and another vector without
+    /// clamping the ratio. An explicitly named alias of [`Vector3::lerp`].
+    pub fn lerp_unclamped(&self, target: &Self, alpha: T) -> Self {
+        self.lerp(target, alpha)
+    }
+
+    /// Linearly interpolates between this vector and another vector, clamping the
+    /// ratio into `[0, 1]` first.
+    pub fn lerp_clamped(&self, target: &Self, alpha: T) -> Self {
+        self.lerp(target, alpha.max(T::zero()).min(T::one()))
+    }
+
+    /// Linearly interpolates between this vector and another vector, using the
+    /// matching component of `alpha` as the ratio for each axis.
+    pub fn lerp_componentwise(&self, target: &Self, alpha: &Self) -> Self {
+        Vector3 {
+            x: self.x.lerp(target.x, alpha.x),
+            y: self.y.lerp(target.y, alpha.y),
+            z: self.z.lerp(target.z, alpha.z),
+        }
+    }
+
+    /// Clamps every component into the unit range `[0.0, 1.0]`.
+    pub fn saturate(&self) -> Self {
+        self.max(&Self::zero()).min(&Self::one())
+    }
+
+    /// Combines the magnitude of each component of this vector with the sign of the
+    /// matching component of `signs`.
+    ///
+    /// Signs are read from the sign bit, so `-0.0` in `signs` makes a component
+    /// negative and `+0.0` makes it positive.
+    pub fn copysign(&self, signs: &Self) -> Self {
+        Vector3 {
+            x: self.x.copysign(signs.x),
+            y: self.y.copysign(signs.y),
+            z: self.z.copysign(signs.z),
+        }
+    }
+
+    /// Computes the unit vector pointing from this point towards `target`.
+    ///
+    /// Returns the zero vector when the points coincide instead of `NaN`.
+    pub fn direction_to(&self, target: &Self) -> Self {
+        let offset = *target - *self;
+        let length = offset.dot(&offset).sqrt();
+        if length == T::zero() {
+            return Self::zero();
+        }
+        offset / length
+    }
+
+    /// Computes `self * mul + add` component-wise as a fused multiply-add.
+    ///
+    /// Each component is rounded only once, which is more precise than the separate
+    /// multiplication and addition of `*self * *mul + *add`, and maps to a single
+    /// instruction on hardware with FMA support.
+    pub fn mul_add(&self, mul: &Self, add: &Self) -> Self {
+        Vector3 {
+            x: self.x.mul_add(mul.x, add.x),
+            y: self.y.mul_add(mul.y, add.y),
+            z: self.z.mul_add(mul.z, add.z),
+        }
+    }
+
+    /// Scales this vector so that its magnitude lies within `[min, max]`, keeping
+    /// its direction.
+    ///
+    /// The zero vector has no direction to scale along, so it is returned as is
+    /// even when `min` is positive.
+    pub fn clamp_length(&self, min: f64, max: f64) -> Self {
+        let length = self.magnitude();
+        if length == 0.0 {
+            return *self;
+        }
+        let clamped = length.max(min).min(max);
+        if clamped == length {
+            return *self;
+        }
+        let scale: T = num::NumCast::from(clamped / length).expect("scale should fit in T");
+        *self * scale
+    }
+
+    /// Computes the projection of this vector onto `target`, which need not be unit
+    /// length. Projecting onto the zero vector yields the zero vector.
+    pub fn project_onto(&self, target: &Self) -> Self {
+        let length_squared = target.dot(target);
+        if length_squared == T::zero() {
+            return Self::zero();
+        }
+        *target * (self.dot(target) / length_squared)
+    }
+
+    /// Projects this vector onto the plane through the origin with the given
+    /// `normal`, removing the component along the normal. The normal need not be
+    /// unit length.
+    pub fn project_onto_plane(&self, normal: &Self) -> Self {
+        *self - self.project_onto(normal)
+    }
+
+    /// Rounds each component to the nearest multiple of the matching `step`
+    /// component, snapping the vector onto a grid.
+    ///
+    /// A zero `step` component leaves that component unchanged.
+    pub fn snap(&self, step: &Self) -> Self {
+        let snap = |value: T, step: T| {
+            if step == T::zero() {
+                value
+            } else {
+                (value / step).round() * step
+            }
+        };
+        Vector3 {
+            x: snap(self.x, step.x),
+            y: snap(self.y, step.y),
+            z: snap(self.z, step.z),
+        }
+    }
+
+    /// Normalizes this vector, also returning its original magnitude, with the
+    /// magnitude computed only once.
+    ///
+    /// The zero vector yields `(zero, 0.0)` instead of `NaN` components.
+    pub fn normalize_and_length(&self) -> (Self, f64) {
+        let length = self.magnitude();
+        if length == 0.0 {
+            return (Self::zero(), 0.0);
+        }
+        let length_t: T = num::NumCast::from(length).expect("length should fit in T");
+        (*self / length_t, length)
+    }
+
+    /// Checks whether this vector has unit length.
+    ///
+    /// Compares the squared magnitude against `1.0` with a tolerance of `1e-5`,
+    /// which avoids the square root and is loose enough to accept the rounding
+    /// left behind by normalizing an `f32` vector.
+    pub fn is_normalized(&self) -> bool {
+        let tolerance: T = num::NumCast::from(1e-5).expect("tolerance should fit in T");
+        (self.dot(self) - T::one()).abs() <= tolerance
+    }
+
+    /// Converts each component from radians to degrees, e.g. for a vector of Euler
+    /// angles.
+    pub fn to_degrees(&self) -> Self {
+        Vector3 {
+            x: self.x.to_degrees(),
+            y: self.y.to_degrees(),
+            z: self.z.to_degrees(),
+        }
+    }
+
+    /// Converts each component from degrees to radians.
+    pub fn to_radians(&self) -> Self {
+        Vector3 {
+            x: self.x.to_radians(),
+            y: self.y.to_radians(),
+            z: self.z.to_radians(),
+        }
+    }
 }
 
 impl Vector3<f64> {
-    /// Scales the vector such that its magnitude becomes 1.
-    pub fn normalize(&mut self) {
+    /// Scales the vector such that its magnitude becomes 1, returning `self` so
+    /// further mutations can be chained.
+    pub fn normalize(&mut self) -> &mut Self {
         *self /= self.magnitude();
+        self
+    }
+
+    /// Scales the vector in place so that its magnitude becomes `length`, keeping
+    /// its direction, and returns `self` for chaining.
+    ///
+    /// Like [`Vector3::normalize`], this yields `NaN` components for the zero vector.
+    pub fn set_magnitude(&mut self, length: f64) -> &mut Self {
+        *self *= length / self.magnitude();
+        self
     }
 }
 
 impl Vector3<f32> {
-    /// Scales the vector such that its magnitude becomes 1.
-    pub fn normalize(&mut self) {
+    /// Scales the vector such that its magnitude becomes 1, returning `self` so
+    /// further mutations can be chained.
+    pub fn normalize(&mut self) -> &mut Self {
         *self /= self.magnitude() as f32;
+        self
+    }
+
+    /// Scales the vector in place so that its magnitude becomes `length`, keeping
+    /// its direction, and returns `self` for chaining.
+    ///
+    /// Like [`Vector3::normalize`], this yields `NaN` components for the zero vector.
+    pub fn set_magnitude(&mut self, length: f32) -> &mut Self {
+        *self *= length / self.magnitude() as f32;
+        self
+    }
+}
+
+impl<T> Vector3<T>
+where
+    T: Vector3Coordinate + num::traits::SaturatingAdd + num::traits::SaturatingSub,
+{
+    /// Adds two vectors component-wise, clamping each component at the numeric bounds.
+    pub fn saturating_add(&self, target: &Self) -> Self {
+        Vector3 {
+            x: self.x.saturating_add(&target.x),
+            y: self.y.saturating_add(&target.y),
+            z: self.z.saturating_add(&target.z),
+        }
+    }
+
+    /// Subtracts two vectors component-wise, clamping each component at the numeric bounds.
+    pub fn saturating_sub(&self, target: &Self) -> Self {
+        Vector3 {
+            x: self.x.saturating_sub(&target.x),
+            y: self.y.saturating_sub(&target.y),
+            z: self.z.saturating_sub(&target.z),
+        }
+    }
+}
+
+impl<T> Vector3<T>
+where
+    T: Vector3Coordinate + num::traits::WrappingAdd + num::traits::WrappingSub,
+{
+    /// Adds two vectors component-wise, wrapping around at the numeric bounds.
+    pub fn wrapping_add(&self, target: &Self) -> Self {
+        Vector3 {
+            x: self.x.wrapping_add(&target.x),
+            y: self.y.wrapping_add(&target.y),
+            z: self.z.wrapping_add(&target.z),
+        }
+    }
+
+    /// Subtracts two vectors component-wise, wrapping around at the numeric bounds.
+    pub fn wrapping_sub(&self, target: &Self) -> Self {
+        Vector3 {
+            x: self.x.wrapping_sub(&target.x),
+            y: self.y.wrapping_sub(&target.y),
+            z: self.z.wrapping_sub(&target.z),
+        }
+    }
+}
+
+impl<T: Vector3Coordinate + num::PrimInt> Vector3<T> {
+    /// Linearly interpolates between two integer vectors, computing each component
+    /// in `f64` and rounding it to the nearest integer (halfway cases away from zero).
+    ///
+    /// `alpha` is not clamped; results beyond the range of `T`, including those of
+    /// an infinite `alpha`, saturate at its bounds. Components that are equal in
+    /// both vectors are returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is `NaN`, which has no meaningful integer result.
+    pub fn lerp_round(&self, target: &Self, alpha: f64) -> Self {
+        assert!(!alpha.is_nan(), "lerp_round alpha must not be NaN");
+        let lerp = |from: T, to: T| {
+            if from == to {
+                return from;
+            }
+            let from = from.to_f64().expect("f64 should handle all values");
+            let to = to.to_f64().expect("f64 should handle all values");
+            let value = (from + (to - from) * alpha).round();
+            num::NumCast::from(value).unwrap_or(if value < 0.0 {
+                T::min_value()
+            } else {
+                T::max_value()
+            })
+        };
+        Vector3 {
+            x: lerp(self.x, target.x),
+            y: lerp(self.y, target.y),
+            z: lerp(self.z, target.z),
+        }
+    }
+}
+
+impl<T: Vector3Coordinate + num::Signed> Vector3<T> {
+    /// Returns the index (0 for X, 1 for Y, 2 for Z) of the component with the
+    /// greatest absolute value.
+    ///
+    /// Ties are broken in favor of the lowest index, so X beats Y and Y beats Z.
+    pub fn dominant_axis(&self) -> usize {
+        let (x, y, z) = (self.x.abs(), self.y.abs(), self.z.abs());
+        if x >= y && x >= z {
+            0
+        } else if y >= z {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Computes the component-wise absolute difference `(|x - x'|, |y - y'|, |z - z'|)`.
+    ///
+    /// Combine with [`Vector3::max_element`] for the L∞ distance between two vectors.
+    pub fn abs_diff(&self, target: &Self) -> Self {
+        Vector3 {
+            x: (self.x - target.x).abs(),
+            y: (self.y - target.y).abs(),
+            z: (self.z - target.z).abs(),
+        }
+    }
+
+    /// Scales this point by `factor` relative to `pivot` instead of the origin,
+    /// computing `pivot + (self - pivot) * factor`.
+    pub fn scale_around(&self, pivot: &Self, factor: T) -> Self {
+        *pivot + (*self - *pivot) * factor
+    }
+}
+
+impl<T: Vector3Coordinate + num::Signed + num::traits::Euclid> Vector3<T> {
+    /// Computes the component-wise Euclidean remainder against `modulus`.
+    ///
+    /// Unlike `%`, the result is never negative, so each component lands in
+    /// `[0, modulus)` for positive moduli. This is what grid wrapping usually wants.
+    pub fn rem_euclid(&self, modulus: &Self) -> Self {
+        Vector3 {
+            x: self.x.rem_euclid(&modulus.x),
+            y: self.y.rem_euclid(&modulus.y),
+            z: self.z.rem_euclid(&modulus.z),
+        }
     }
 }
 
@@ -122,21 +455,118 @@ impl<T: Vector3Coordinate> Vector3<T> {
     ///
     /// let vector3 = Vector3::new(1.0, 2.0, 3.0);
     /// ```
-    pub fn new(x: T, y: T, z: T) -> Self {
+    pub const fn new(x: T, y: T, z: T) -> Self {
         Vector3 { x, y, z }
     }
 
+    /// Creates a new Vector3 with all three components set to `value`.
+    pub const fn splat(value: T) -> Self {
+        Vector3 {
+            x: value,
+            y: value,
+            z: value,
+        }
+    }
+
+    /// Creates a unit vector along the X-axis.
+    pub fn unit_x() -> Self {
+        Vector3::new(T::one(), T::zero(), T::zero())
+    }
+
+    /// Creates a unit vector along the Y-axis.
+    pub fn unit_y() -> Self {
+        Vector3::new(T::zero(), T::one(), T::zero())
+    }
+
+    /// Creates a unit vector along the Z-axis.
+    pub fn unit_z() -> Self {
+        Vector3::new(T::zero(), T::zero(), T::one())
+    }
+
+    /// Creates a vector with all components set to zero.
+    pub fn zero() -> Self {
+        Vector3::splat(T::zero())
+    }
+
+    /// Creates a vector with all components set to one.
+    pub fn one() -> Self {
+        Vector3::splat(T::one())
+    }
+
+    /// Creates a new Vector3 from the first three items of an iterator.
+    ///
+    /// Returns `None` if the iterator yields fewer than three items. Any items after
+    /// the third are left unconsumed.
+    pub fn from_iter_opt<I: IntoIterator<Item = T>>(iter: I) -> Option<Self> {
+        let mut iter = iter.into_iter();
+        Some(Vector3 {
+            x: iter.next()?,
+            y: iter.next()?,
+            z: iter.next()?,
+        })
+    }
+
+    /// Creates a new Vector3 by calling `f` with each component index, `0` for X,
+    /// `1` for Y and `2` for Z.
+    pub fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> Self {
+        Vector3 {
+            x: f(0),
+            y: f(1),
+            z: f(2),
+        }
+    }
+
+    /// Creates a new Vector3 by calling a fallible `f` with each component index,
+    /// returning the first error without calling `f` for the remaining components.
+    pub fn try_from_fn<E, F: FnMut(usize) -> Result<T, E>>(mut f: F) -> Result<Self, E> {
+        Ok(Vector3 {
+            x: f(0)?,
+            y: f(1)?,
+            z: f(2)?,
+        })
+    }
+
+    /// Adds every vector in `others` into this vector in place, returning `self` for
+    /// chaining.
+    pub fn accumulate(&mut self, others: &[Self]) -> &mut Self {
+        for other in others {
+            *self += *other;
+        }
+        self
+    }
+
     /// Computes the magnitude (length) of the vector.
     pub fn magnitude(&self) -> f64 {
         let mag2 = self.x * self.x + self.y * self.y + self.z * self.z;
         mag2.to_f64().expect("f64 should handle all values").sqrt()
     }
 
+    /// Computes the magnitude (length) of the vector in the requested float
+    /// precision, e.g. `magnitude_as::<f32>()`.
+    ///
+    /// The components are converted to `F` before squaring, so the whole computation
+    /// happens in `F`.
+    pub fn magnitude_as<F: num::Float + num::NumCast>(&self) -> F {
+        let cast =
+            |value: T| -> F { num::NumCast::from(value).expect("F should handle all values") };
+        let (x, y, z) = (cast(self.x), cast(self.y), cast(self.z));
+        (x * x + y * y + z * z).sqrt()
+    }
+
     /// Computes the dot product between this vector and another vector.
     pub fn dot(&self, target: &Self) -> T {
         self.x * target.x + self.y * target.y + self.z * target.z
     }
 
+    /// Computes the dot product between this vector and another vector, converting
+    /// each component to `f64` first so that integer products cannot overflow `T`.
+    pub fn dot_f64(&self, target: &Self) -> f64 {
+        let to_f64 = |value: T| value.to_f64().expect("f64 should handle all values");
+        to_f64(self.x) * to_f64(target.x)
+            + to_f64(self.y) * to_f64(target.y)
+            + to_f64(self.z) * to_f64(target.z)
+    }
+
     /// Computes the cross product between this vector and another vector.
     pub fn cross(&self, target: &Self) -> Self {
         Vector3 {
@@ -146,6 +576,122 @@ impl<T: Vector3Coordinate> Vector3<T> {
         }
     }
 
+    /// Like [`Vector3::dot`], but accepts anything convertible into a vector, such
+    /// as a tuple or an array: `v.dot_into((1.0, 0.0, 0.0))`.
+    pub fn dot_into(&self, target: impl Into<Self>) -> T {
+        self.dot(&target.into())
+    }
+
+    /// Like [`Vector3::cross`], but accepts anything convertible into a vector.
+    pub fn cross_into(&self, target: impl Into<Self>) -> Self {
+        self.cross(&target.into())
+    }
+
+    /// Multiplies the vectors component-wise (the Hadamard product). Same as
+    /// `*self * *other`; `*` between two vectors is not the dot or cross product.
+    pub fn component_mul(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    /// Divides the vectors component-wise. Same as `*self / *other`.
+    pub fn component_div(&self, other: &Self) -> Self {
+        *self / *other
+    }
+
+    /// Computes the volume of an axis-aligned box whose full extents (width, height
+    /// and depth, not half-extents) are the components of this vector.
+    pub fn box_volume(&self) -> T {
+        self.x * self.y * self.z
+    }
+
+    /// Computes the surface area of an axis-aligned box whose full extents (not
+    /// half-extents) are the components of this vector.
+    pub fn box_surface_area(&self) -> T {
+        let two = T::one() + T::one();
+        two * (self.x * self.y + self.y * self.z + self.z * self.x)
+    }
+
+    /// Applies `f` to each component together with its axis index, calling
+    /// `f(0, x)`, `f(1, y)` and `f(2, z)`.
+    pub fn map_with_index<F: Fn(usize, T) -> T>(&self, f: F) -> Self {
+        Vector3 {
+            x: f(0, self.x),
+            y: f(1, self.y),
+            z: f(2, self.z),
+        }
+    }
+
+    /// Folds the components into an accumulator, applying `f` to `x`, `y` and `z`
+    /// in order.
+    pub fn fold<A, F: Fn(A, T) -> A>(&self, init: A, f: F) -> A {
+        f(f(f(init, self.x), self.y), self.z)
+    }
+
+    /// Builds a vector from the components at indices `i`, `j` and `k`, where `0`,
+    /// `1` and `2` select `x`, `y` and `z`, like a GLSL swizzle: `swizzle(2, 1, 0)`
+    /// is `zyx` and `swizzle(0, 0, 0)` is `xxx`.
+    ///
+    /// Returns `None` if any index is out of range.
+    pub fn swizzle(&self, i: usize, j: usize, k: usize) -> Option<Self> {
+        let component = |index: usize| match index {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            2 => Some(self.z),
+            _ => None,
+        };
+        Some(Vector3 {
+            x: component(i)?,
+            y: component(j)?,
+            z: component(k)?,
+        })
+    }
+
+    /// Combines the components with a binary operation as `f(f(x, y), z)`, e.g.
+    /// `v.reduce(|a, b| a + b)` for the component sum.
+    pub fn reduce<F: Fn(T, T) -> T>(&self, f: F) -> T {
+        f(f(self.x, self.y), self.z)
+    }
+
+    /// Checks whether `pred` holds for every component.
+    pub fn all<F: Fn(T) -> bool>(&self, pred: F) -> bool {
+        pred(self.x) && pred(self.y) && pred(self.z)
+    }
+
+    /// Checks whether `pred` holds for at least one component.
+    pub fn any<F: Fn(T) -> bool>(&self, pred: F) -> bool {
+        pred(self.x) || pred(self.y) || pred(self.z)
+    }
+
+    /// Reflects this point through `center`, returning `2 * center - self`.
+    ///
+    /// Each component is computed as `center` plus or minus its distance from
+    /// `self`, so integer types only overflow when the reflected component itself
+    /// lies outside the range of `T`, such as a negative result for unsigned types.
+    /// Like any integer overflow, that panics in debug builds.
+    pub fn reflect_across_point(&self, center: &Self) -> Self {
+        let reflect = |point: T, center: T| {
+            if point <= center {
+                center + (center - point)
+            } else {
+                center - (point - center)
+            }
+        };
+        Vector3 {
+            x: reflect(self.x, center.x),
+            y: reflect(self.y, center.y),
+            z: reflect(self.z, center.z),
+        }
+    }
+
+    /// Computes the outer product `self * targetᵀ` as a row-major 3x3 matrix.
+    pub fn outer_product(&self, target: &Self) -> [[T; 3]; 3] {
+        [
+            [self.x * target.x, self.x * target.y, self.x * target.z],
+            [self.y * target.x, self.y * target.y, self.y * target.z],
+            [self.z * target.x, self.z * target.y, self.z * target.z],
+        ]
+    }
+
     /// Computes the component-wise maximum of this vector and another vector.
     pub fn max(&self, target: &Self) -> Self {
         let x = if self.x > target.x { self.x } else { target.x };
@@ -162,6 +708,130 @@ impl<T: Vector3Coordinate> Vector3<T> {
         Vector3 { x, y, z }
     }
 
+    /// Computes the component-wise minimum of this vector and the scalar `value`,
+    /// capping every component at `value`.
+    pub fn min_scalar(&self, value: T) -> Self {
+        self.min(&Self::splat(value))
+    }
+
+    /// Computes the component-wise maximum of this vector and the scalar `value`,
+    /// raising every component to at least `value`.
+    pub fn max_scalar(&self, value: T) -> Self {
+        self.max(&Self::splat(value))
+    }
+
+    /// Computes the axis-aligned bounding box of a set of points, returned as its
+    /// `(min, max)` corners, or `None` if there are no points.
+    pub fn aabb<I: IntoIterator<Item = Self>>(points: I) -> Option<(Self, Self)> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold((first, first), |(min, max), point| {
+            (min.min(&point), max.max(&point))
+        }))
+    }
+
+    /// Checks whether every component of this vector is `<=` the matching
+    /// component of `other`.
+    ///
+    /// Unlike the derived `PartialOrd`, which compares lexicographically (`x` first,
+    /// then `y`, then `z`), this is a true partial order: two vectors can be
+    /// incomparable, with neither `componentwise_le` nor `componentwise_ge` holding.
+    pub fn componentwise_le(&self, other: &Self) -> bool {
+        self.x <= other.x && self.y <= other.y && self.z <= other.z
+    }
+
+    /// Checks whether every component of this vector is `>=` the matching
+    /// component of `other`. See [`Vector3::componentwise_le`].
+    pub fn componentwise_ge(&self, other: &Self) -> bool {
+        self.x >= other.x && self.y >= other.y && self.z >= other.z
+    }
+
+    /// Checks whether `point` lies within the axis-aligned box spanned by the `min`
+    /// and `max` corners. The box is inclusive: points on a face, edge or corner
+    /// are contained.
+    pub fn aabb_contains(min: &Self, max: &Self, point: &Self) -> bool {
+        min.componentwise_le(point) && point.componentwise_le(max)
+    }
+
+    /// Clamps each component into the axis-aligned box spanned by the `min` and
+    /// `max` corners, returning the closest point of the box.
+    ///
+    /// In debug builds this panics if any component of `min` exceeds `max`.
+    pub fn clamp_to_aabb(&self, min: &Self, max: &Self) -> Self {
+        debug_assert!(
+            min.componentwise_le(max),
+            "aabb min corner must not exceed the max corner"
+        );
+        self.max(min).min(max)
+    }
+
+    /// Computes both the component-wise minimum and maximum of this vector and
+    /// another vector in a single pass, returned as `(min, max)`.
+    ///
+    /// Each component is picked exactly as [`Vector3::min`] and [`Vector3::max`]
+    /// would, including for `NaN` and signed zeros.
+    pub fn min_max(&self, target: &Self) -> (Self, Self) {
+        let pick = |a: T, b: T| (if a < b { a } else { b }, if a > b { a } else { b });
+        let (min_x, max_x) = pick(self.x, target.x);
+        let (min_y, max_y) = pick(self.y, target.y);
+        let (min_z, max_z) = pick(self.z, target.z);
+        (
+            Vector3::new(min_x, min_y, min_z),
+            Vector3::new(max_x, max_y, max_z),
+        )
+    }
+
+    /// Returns the components sorted in ascending order.
+    ///
+    /// Floats are ordered as by `total_cmp`: `-0.0` sorts before `0.0`, and `NaN`
+    /// sorts after every other value, or before them if its sign bit is set.
+    /// Values that compare equal or unordered are tie-broken by `total_cmp` on
+    /// their `f64` conversion, and are left in place if that conversion fails.
+    pub fn sorted_components(&self) -> [T; 3] {
+        let compare = |a: &T, b: &T| match a.partial_cmp(b) {
+            Some(std::cmp::Ordering::Equal) | None => match (a.to_f64(), b.to_f64()) {
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                _ => std::cmp::Ordering::Equal,
+            },
+            Some(ordering) => ordering,
+        };
+        let mut components = [self.x, self.y, self.z];
+        for (i, j) in [(0, 1), (1, 2), (0, 1)] {
+            if compare(&components[i], &components[j]) == std::cmp::Ordering::Greater {
+                components.swap(i, j);
+            }
+        }
+        components
+    }
+
+    /// Returns the smallest of the three components.
+    pub fn min_element(&self) -> T {
+        self.sorted_components()[0]
+    }
+
+    /// Returns the middle of the three component values.
+    pub fn median_element(&self) -> T {
+        self.sorted_components()[1]
+    }
+
+    /// Returns the largest of the three components.
+    pub fn max_element(&self) -> T {
+        self.sorted_components()[2]
+    }
+
+    /// Compares each component against `edge`, like GLSL's `step(edge, x)`.
+    ///
+    /// Each component becomes zero if it is below the matching `edge` component
+    /// and one otherwise.
+    pub fn step(&self, edge: &Self) -> Self {
+        let step = |value: T, edge: T| if value < edge { T::zero() } else { T::one() };
+        Vector3 {
+            x: step(self.x, edge.x),
+            y: step(self.y, edge.y),
+            z: step(self.z, edge.z),
+        }
+    }
+
     /// Computes the angle in radians between this vector and another vector.
     pub fn angle(&self, target: &Self) -> f64 {
         let dot_product = self
@@ -169,7 +839,13 @@ impl<T: Vector3Coordinate> Vector3<T> {
             .to_f64()
             .expect("f64 should handle all values");
         let magnitude_product = self.magnitude() * target.magnitude();
-        (dot_product / magnitude_product).acos()
+        // Rounding can push the cosine slightly outside [-1, 1], where acos is NaN.
+        (dot_product / magnitude_product).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Like [`Vector3::angle`], but accepts anything convertible into a vector.
+    pub fn angle_into(&self, target: impl Into<Self>) -> f64 {
+        self.angle(&target.into())
     }
 
     /// Computes the angle in degrees between this vector and another vector.
@@ -191,6 +867,51 @@ impl<T: Vector3Coordinate> Vector3<T> {
     pub const fn get_z(&self) -> T {
         self.z
     }
+
+    /// Borrows all three components mutably at once, as `(x, y, z)`.
+    pub fn components_mut(&mut self) -> (&mut T, &mut T, &mut T) {
+        (&mut self.x, &mut self.y, &mut self.z)
+    }
+
+    /// Returns a copy with the X component set to zero, flattening onto the YZ plane.
+    pub fn with_x_zero(&self) -> Self {
+        Vector3 {
+            x: T::zero(),
+            ..*self
+        }
+    }
+
+    /// Returns a copy with the Y component set to zero, flattening onto the XZ
+    /// plane (the ground in a Y-up world).
+    pub fn with_y_zero(&self) -> Self {
+        Vector3 {
+            y: T::zero(),
+            ..*self
+        }
+    }
+
+    /// Returns a copy with the Z component set to zero, flattening onto the XY plane.
+    pub fn with_z_zero(&self) -> Self {
+        Vector3 {
+            z: T::zero(),
+            ..*self
+        }
+    }
+
+    /// Returns a pointer to the first of the three contiguous components, for
+    /// passing the vector to C code expecting a `T[3]`.
+    ///
+    /// The pointer is valid for reads of three `T`s for as long as the vector is
+    /// borrowed.
+    pub const fn as_ptr(&self) -> *const T {
+        (self as *const Self).cast::<T>()
+    }
+
+    /// Returns a mutable pointer to the first of the three contiguous components.
+    /// See [`Vector3::as_ptr`] for the layout guarantee.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        (self as *mut Self).cast::<T>()
+    }
 }
 
 impl<T: Vector3Coordinate> std::fmt::Display for Vector3<T> {
@@ -199,6 +920,28 @@ impl<T: Vector3Coordinate> std::fmt::Display for Vector3<T> {
     }
 }
 
+macro_rules! impl_radix_fmt {
+    ($($trait:ident),*) => {
+        $(
+            /// Formats each component with the same flags, e.g. `{:#x}` gives
+            /// `Vector3(0x1, 0x2, 0x3)`.
+            impl<T: Vector3Coordinate + std::fmt::$trait> std::fmt::$trait for Vector3<T> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str("Vector3(")?;
+                    std::fmt::$trait::fmt(&self.x, f)?;
+                    f.write_str(", ")?;
+                    std::fmt::$trait::fmt(&self.y, f)?;
+                    f.write_str(", ")?;
+                    std::fmt::$trait::fmt(&self.z, f)?;
+                    f.write_str(")")
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_fmt!(LowerHex, UpperHex, Binary, Octal);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,6 +953,13 @@ mod tests {
         assert_eq!(calc_angle, angle);
     }
 
+    #[test]
+    fn angle_with_itself() {
+        let vec = Vector3::new(-4.9, -0.1, -1.0);
+        assert_eq!(vec.angle(&vec), 0.0);
+        assert_eq!(vec.angle(&(vec * -1.0)), std::f64::consts::PI);
+    }
+
     #[test]
     fn create() {
         let my_vec = Vector3::new(1.3, 0.0, -5.35501);
@@ -219,29 +969,387 @@ mod tests {
     }
 
     #[test]
-    fn sum() {
-        let vec1 = Vector3::new(1.0, 2.0, 3.0);
-        let vec2 = Vector3::new(5.0, 0.0, -1.0);
-        assert_eq!(vec1 + vec2, Vector3::new(6.0, 2.0, 2.0));
+    fn splat() {
+        assert_eq!(Vector3::splat(3), Vector3::new(3, 3, 3));
+        assert_eq!(Vector3::splat(-0.5), Vector3::new(-0.5, -0.5, -0.5));
     }
 
     #[test]
-    fn normalization() {
-        let mut test_vec: Vector3<f64> = Vector3::new(1.0, 2.3, 100.123);
-        test_vec.normalize();
+    fn generic_constructors() {
+        assert_eq!(Vector3::<f32>::unit_x(), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(Vector3::<f32>::unit_y(), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(Vector3::<f32>::unit_z(), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(Vector3::<f64>::unit_x(), consts::X_AXIS);
+        assert_eq!(Vector3::<i32>::zero(), Vector3::new(0, 0, 0));
+        assert_eq!(Vector3::<u8>::one(), Vector3::new(1, 1, 1));
         assert_eq!(
-            test_vec,
-            Vector3::new(0.00998458316076644, 0.02296454126976281, 0.9996864198054183)
+            Vector3::<i64>::unit_x() + Vector3::unit_y() + Vector3::unit_z(),
+            Vector3::one()
         );
-        assert!((1.0 - test_vec.magnitude()).abs() < 0.00000001);
     }
 
     #[test]
-    fn lerp() {
-        let start = Vector3::new(0.0, 0.0, 0.0);
-        let end = Vector3::new(1.0, 2.0, 3.0);
-        let lerp_result = start.lerp(&end, 0.75);
-        assert_eq!(lerp_result, Vector3::new(0.75, 1.5, 2.25));
+    fn from_iterator() {
+        assert_eq!(Vector3::from_iter_opt([1, 2]), None);
+        assert_eq!(
+            Vector3::from_iter_opt([1, 2, 3]),
+            Some(Vector3::new(1, 2, 3))
+        );
+        assert_eq!(
+            Vector3::from_iter_opt(vec![1.0, 2.0, 3.0, 4.0]),
+            Some(Vector3::new(1.0, 2.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn sum() {
+        let vec1 = Vector3::new(1.0, 2.0, 3.0);
+        let vec2 = Vector3::new(5.0, 0.0, -1.0);
+        assert_eq!(vec1 + vec2, Vector3::new(6.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn accumulation() {
+        let others = [
+            Vector3::new(1, 2, 3),
+            Vector3::new(-4, 5, 0),
+            Vector3::new(10, 0, -7),
+        ];
+        let mut total = Vector3::new(1, 1, 1);
+        total.accumulate(&others);
+        assert_eq!(
+            total,
+            Vector3::new(1, 1, 1) + others[0] + others[1] + others[2]
+        );
+
+        total.accumulate(&[]);
+        assert_eq!(total, Vector3::new(8, 8, -3));
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        let vec = Vector3::new(1.13, -0.37, 2.88);
+        assert_eq!(
+            vec.snap(&Vector3::splat(0.25)),
+            Vector3::new(1.25, -0.25, 3.0)
+        );
+        assert_eq!(
+            vec.snap(&Vector3::new(1.0, 0.5, 0.0)),
+            Vector3::new(1.0, -0.5, 2.88)
+        );
+        assert_eq!(
+            Vector3::new(7.0f32, 12.0, -3.0).snap(&Vector3::new(5.0, 10.0, 2.0)),
+            Vector3::new(5.0, 10.0, -4.0)
+        );
+    }
+
+    #[test]
+    fn normalize_with_length() {
+        let vec: Vector3<f64> = Vector3::new(2.0, -3.0, 6.0);
+        let (unit, length) = vec.normalize_and_length();
+        assert_eq!(length, vec.magnitude());
+        assert_eq!(length, 7.0);
+        assert!(unit.is_normalized());
+        assert!((unit * length).fuzzy_equal(&vec, 1e-12));
+
+        let (unit, length) = Vector3::<f32>::zero().normalize_and_length();
+        assert_eq!((unit, length), (Vector3::zero(), 0.0));
+    }
+
+    #[test]
+    fn is_normalized() {
+        let mut unit: Vector3<f32> = Vector3::new(3.0, -7.0, 11.0);
+        unit.normalize();
+        assert!(unit.is_normalized());
+        assert!(Vector3::new(1.0 + 1e-7, 0.0, 0.0).is_normalized());
+        assert!(!Vector3::new(1.01, 0.0, 0.0).is_normalized());
+        assert!(!Vector3::new(1.0, 1.0, 1.0).is_normalized());
+        assert!(!Vector3::<f64>::zero().is_normalized());
+    }
+
+    #[test]
+    fn float_methods_need_no_rand_bound() {
+        fn unit_range<T: Vector3Coordinate + num::Float>(vec: Vector3<T>) -> Vector3<T> {
+            vec.lerp_clamped(&vec.saturate(), T::one())
+        }
+        assert_eq!(
+            unit_range(Vector3::new(-1.0, 0.5, 2.0)),
+            Vector3::new(0.0, 0.5, 1.0)
+        );
+    }
+
+    #[test]
+    fn degrees_radians() {
+        use std::f64::consts::PI;
+        let angles = Vector3::new(PI, PI / 2.0, 0.0);
+        assert!(angles
+            .to_degrees()
+            .fuzzy_equal(&Vector3::new(180.0, 90.0, 0.0), 1e-12));
+        assert!(angles.to_degrees().to_radians().fuzzy_equal(&angles, 1e-12));
+        let angles = Vector3::new(180.0f32, 90.0, 0.0);
+        assert!(angles.to_radians().fuzzy_equal(
+            &Vector3::new(std::f32::consts::PI, std::f32::consts::FRAC_PI_2, 0.0),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn mutable_components() {
+        let mut vec = Vector3::new(1, 2, 3);
+        let (x, y, z) = vec.components_mut();
+        std::mem::swap(x, z);
+        *y *= 10;
+        assert_eq!(vec, Vector3::new(3, 20, 1));
+    }
+
+    #[test]
+    fn zeroed_components() {
+        let vec = Vector3::new(1.5, -2.0, 3.0);
+        assert_eq!(vec.with_x_zero(), Vector3::new(0.0, -2.0, 3.0));
+        assert_eq!(vec.with_y_zero(), Vector3::new(1.5, 0.0, 3.0));
+        assert_eq!(vec.with_z_zero(), Vector3::new(1.5, -2.0, 0.0));
+        assert_eq!(Vector3::new(4u8, 5, 6).with_y_zero(), Vector3::new(4, 0, 6));
+    }
+
+    #[test]
+    #[allow(unsafe_code)]
+    fn raw_pointers() {
+        let mut vec = Vector3::new(1.5f32, -2.0, 3.25);
+        let components = unsafe { std::slice::from_raw_parts(vec.as_ptr(), 3) };
+        assert_eq!(components, [1.5, -2.0, 3.25]);
+
+        let ptr = vec.as_mut_ptr();
+        unsafe {
+            *ptr.add(2) = 4.0;
+        }
+        assert_eq!(vec, Vector3::new(1.5, -2.0, 4.0));
+        assert_eq!(std::mem::size_of::<Vector3<f64>>(), 3 * 8);
+    }
+
+    #[test]
+    fn radix_formatting() {
+        let vec: Vector3<u32> = Vector3::new(1, 10, 255);
+        assert_eq!(format!("{vec:x}"), "Vector3(1, a, ff)");
+        assert_eq!(format!("{vec:#x}"), "Vector3(0x1, 0xa, 0xff)");
+        assert_eq!(format!("{vec:#X}"), "Vector3(0x1, 0xA, 0xFF)");
+        assert_eq!(format!("{vec:#b}"), "Vector3(0b1, 0b1010, 0b11111111)");
+        assert_eq!(format!("{vec:04b}"), "Vector3(0001, 1010, 11111111)");
+        assert_eq!(format!("{vec:#o}"), "Vector3(0o1, 0o12, 0o377)");
+    }
+
+    #[test]
+    fn borrowed_scalar_ops() {
+        let vec = Vector3::new(2.0, -4.0, 6.0);
+        let scalar = 2.0;
+        let (vec_ref, scalar_ref) = (&vec, &scalar);
+        assert_eq!(vec_ref * scalar_ref, vec * scalar);
+        assert_eq!(vec_ref / scalar_ref, vec / scalar);
+
+        fn scale<'a, T: Vector3Coordinate>(v: &'a Vector3<T>, s: &'a T) -> Vector3<T>
+        where
+            &'a Vector3<T>: std::ops::Mul<&'a T, Output = Vector3<T>>,
+        {
+            v * s
+        }
+        assert_eq!(scale(&Vector3::new(1, 2, 3), &3), Vector3::new(3, 6, 9));
+    }
+
+    #[test]
+    fn remainder() {
+        let mut grid: Vector3<i32> = Vector3::new(7, 12, -5);
+        assert_eq!(grid % 4, Vector3::new(3, 0, -1));
+        assert_eq!(grid % Vector3::new(2, 5, 3), Vector3::new(1, 2, -2));
+
+        grid %= Vector3::new(4, 5, 6);
+        assert_eq!(grid, Vector3::new(3, 2, -5));
+        grid %= 2;
+        assert_eq!(grid, Vector3::new(1, 0, -1));
+    }
+
+    #[test]
+    fn dominant() {
+        assert_eq!(Vector3::new(1.0, -5.0, 3.0).dominant_axis(), 1);
+        assert_eq!(Vector3::new(-7, 2, 6).dominant_axis(), 0);
+        assert_eq!(Vector3::new(0.5, 0.25, -0.75).dominant_axis(), 2);
+
+        assert_eq!(Vector3::new(-2, 2, 2).dominant_axis(), 0);
+        assert_eq!(Vector3::new(1, -3, 3).dominant_axis(), 1);
+        assert_eq!(Vector3::new(0, 0, 0).dominant_axis(), 0);
+    }
+
+    #[test]
+    fn absolute_difference() {
+        let vec1 = Vector3::new(1.0, -2.0, 3.5);
+        let vec2 = Vector3::new(4.0, 1.0, 3.0);
+        assert_eq!(vec1.abs_diff(&vec2), Vector3::new(3.0, 3.0, 0.5));
+        assert_eq!(vec1.abs_diff(&vec2).max_element(), 3.0);
+
+        let vec1: Vector3<i64> = Vector3::new(-5, 0, 7);
+        let vec2 = Vector3::new(5, -3, 7);
+        assert_eq!(vec1.abs_diff(&vec2), Vector3::new(10, 3, 0));
+        assert_eq!(vec2.abs_diff(&vec1), vec1.abs_diff(&vec2));
+    }
+
+    #[test]
+    fn integer_lerp() {
+        let from: Vector3<i32> = Vector3::new(0, 10, -3);
+        let to = Vector3::new(5, 20, 4);
+        assert_eq!(from.lerp_round(&to, 0.5), Vector3::new(3, 15, 1));
+        assert_eq!(from.lerp_round(&to, 0.0), from);
+        assert_eq!(from.lerp_round(&to, 1.0), to);
+        assert_eq!(
+            Vector3::new(0, 0, 0).lerp_round(&Vector3::new(-5, -1, 1), 0.5),
+            Vector3::new(-3, -1, 1)
+        );
+    }
+
+    #[test]
+    fn integer_lerp_saturates() {
+        let from: Vector3<u8> = Vector3::new(0, 200, 100);
+        let to = Vector3::new(10, 250, 0);
+        assert_eq!(from.lerp_round(&to, 2.0), Vector3::new(20, 255, 0));
+        let from: Vector3<i64> = Vector3::new(0, 0, 0);
+        let to = Vector3::new(i64::MAX, i64::MIN, 1);
+        assert_eq!(
+            from.lerp_round(&to, 4.0),
+            Vector3::new(i64::MAX, i64::MIN, 4)
+        );
+    }
+
+    #[test]
+    fn integer_lerp_infinite_alpha() {
+        let from: Vector3<i32> = Vector3::new(0, 5, 7);
+        let to = Vector3::new(1, -5, 7);
+        assert_eq!(
+            from.lerp_round(&to, f64::INFINITY),
+            Vector3::new(i32::MAX, i32::MIN, 7)
+        );
+        assert_eq!(
+            from.lerp_round(&to, f64::NEG_INFINITY),
+            Vector3::new(i32::MIN, i32::MAX, 7)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lerp_round alpha must not be NaN")]
+    fn integer_lerp_nan_alpha() {
+        Vector3::new(1, 2, 3).lerp_round(&Vector3::new(4, 5, 6), f64::NAN);
+    }
+
+    #[test]
+    fn scale_around_pivot() {
+        let point = Vector3::new(3, -2, 5);
+        assert_eq!(point.scale_around(&Vector3::zero(), 2), point * 2);
+        assert_eq!(
+            point.scale_around(&Vector3::new(1, 1, 1), 3),
+            Vector3::new(7, -8, 13)
+        );
+        let pivot = Vector3::new(2.0, 2.0, 2.0);
+        assert_eq!(
+            Vector3::new(4.0, 0.0, 2.0).scale_around(&pivot, 0.5),
+            Vector3::new(3.0, 1.0, 2.0)
+        );
+        assert_eq!(pivot.scale_around(&pivot, 10.0), pivot);
+    }
+
+    #[test]
+    fn euclidean_remainder() {
+        let grid: Vector3<i32> = Vector3::new(-1, -9, 7);
+        let size = Vector3::new(4, 4, 4);
+        assert_eq!(grid.rem_euclid(&size), Vector3::new(3, 3, 3));
+
+        let point: Vector3<f64> = Vector3::new(-0.5, -10.0, 2.5);
+        let size = Vector3::new(2.0, 4.0, 2.0);
+        assert_eq!(point.rem_euclid(&size), Vector3::new(1.5, 2.0, 0.5));
+    }
+
+    #[test]
+    fn from_index_fn() {
+        assert_eq!(Vector3::from_fn(|i| i as u32 * 2), Vector3::new(0, 2, 4));
+        let parts = ["1.5", "-2", "3e2"];
+        let parsed = Vector3::try_from_fn(|i| parts[i].parse::<f64>());
+        assert_eq!(parsed, Ok(Vector3::new(1.5, -2.0, 300.0)));
+
+        let mut calls = 0;
+        let parts = ["1", "oops", "3"];
+        let parsed = Vector3::try_from_fn(|i| {
+            calls += 1;
+            parts[i].parse::<i32>()
+        });
+        assert!(parsed.is_err());
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn chained_mutators() {
+        let mut vec: Vector3<f64> = Vector3::new(3.0, 0.0, 4.0);
+        vec.normalize().set_magnitude(2.0);
+        assert!(vec.fuzzy_equal(&Vector3::new(1.2, 0.0, 1.6), 1e-12));
+
+        let mut sum: Vector3<f32> = Vector3::zero();
+        let length = sum
+            .accumulate(&[Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 3.0, 0.0)])
+            .set_magnitude(10.0)
+            .magnitude();
+        assert_eq!(length, 10.0);
+        assert_eq!(sum, Vector3::new(0.0, 10.0, 0.0));
+    }
+
+    #[test]
+    fn normalization() {
+        let mut test_vec: Vector3<f64> = Vector3::new(1.0, 2.3, 100.123);
+        test_vec.normalize();
+        assert_eq!(
+            test_vec,
+            Vector3::new(0.00998458316076644, 0.02296454126976281, 0.9996864198054183)
+        );
+        assert!((1.0 - test_vec.magnitude()).abs() < 0.00000001);
+    }
+
+    #[test]
+    fn lerp() {
+        let start = Vector3::new(0.0, 0.0, 0.0);
+        let end = Vector3::new(1.0, 2.0, 3.0);
+        let lerp_result = start.lerp(&end, 0.75);
+        assert_eq!(lerp_result, Vector3::new(0.75, 1.5, 2.25));
+    }
+
+    #[test]
+    fn lerp_clamping() {
+        let start = Vector3::new(0.0, 0.0, 0.0);
+        let end = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(start.lerp(&end, 1.5), Vector3::new(1.5, 3.0, 4.5));
+        assert_eq!(start.lerp_unclamped(&end, 1.5), Vector3::new(1.5, 3.0, 4.5));
+        assert_eq!(start.lerp_clamped(&end, 1.5), end);
+        assert_eq!(start.lerp_clamped(&end, -0.5), start);
+        assert_eq!(start.lerp_clamped(&end, 0.5), start.lerp(&end, 0.5));
+    }
+
+    #[test]
+    fn lerp_per_component() {
+        let start = Vector3::new(0.0, 0.0, 0.0);
+        let end = Vector3::new(2.0, 4.0, 6.0);
+        let alpha = Vector3::new(0.0, 0.5, 1.0);
+        assert_eq!(
+            start.lerp_componentwise(&end, &alpha),
+            Vector3::new(0.0, 2.0, 6.0)
+        );
+        let uniform = Vector3::new(0.75, 0.75, 0.75);
+        assert_eq!(
+            start.lerp_componentwise(&end, &uniform),
+            start.lerp(&end, 0.75)
+        );
+    }
+
+    #[test]
+    fn magnitude_precision() {
+        let vec: Vector3<f32> = Vector3::new(2.0, 3.0, 6.0);
+        let single: f32 = vec.magnitude_as();
+        assert_eq!(single, 7.0);
+        assert_eq!(vec.magnitude_as::<f64>(), vec.magnitude());
+
+        let bytes: Vector3<u8> = Vector3::new(200, 200, 100);
+        assert_eq!(bytes.magnitude_as::<f32>(), 300.0);
     }
 
     #[test]
@@ -252,6 +1360,14 @@ mod tests {
         assert_eq!(dot_result, 2.0);
     }
 
+    #[test]
+    fn dot_product_f64() {
+        let vec: Vector3<u8> = Vector3::new(200, 100, 255);
+        assert_eq!(vec.dot_f64(&vec), 40000.0 + 10000.0 + 65025.0);
+        let vec2 = Vector3::new(1.5, -2.0, 0.5);
+        assert_eq!(vec2.dot_f64(&vec2), vec2.dot(&vec2));
+    }
+
     #[test]
     fn cross_product() {
         let vec1 = Vector3::new(1.0, 0.0, 0.0);
@@ -260,6 +1376,150 @@ mod tests {
         assert_eq!(cross_result, Vector3::new(0.0, 0.0, 1.0));
     }
 
+    #[test]
+    fn box_measurements() {
+        let unit_cube = Vector3::new(1.0, 1.0, 1.0);
+        assert_eq!(unit_cube.box_volume(), 1.0);
+        assert_eq!(unit_cube.box_surface_area(), 6.0);
+
+        let rectangular: Vector3<u32> = Vector3::new(2, 3, 4);
+        assert_eq!(rectangular.box_volume(), 24);
+        assert_eq!(rectangular.box_surface_area(), 52);
+    }
+
+    #[test]
+    fn map_indexed() {
+        let vec = Vector3::new(1.5, -2.0, 3.0);
+        let zero_axis = |axis: usize| vec.map_with_index(|i, c| if i == axis { 0.0 } else { c });
+        assert_eq!(zero_axis(0), Vector3::new(0.0, -2.0, 3.0));
+        assert_eq!(zero_axis(1), Vector3::new(1.5, 0.0, 3.0));
+        assert_eq!(zero_axis(2), Vector3::new(1.5, -2.0, 0.0));
+
+        let scales = [2, 3, 4];
+        let scaled = Vector3::new(1, 1, 1).map_with_index(|i, c| c * scales[i]);
+        assert_eq!(scaled, Vector3::new(2, 3, 4));
+    }
+
+    #[test]
+    fn fold_components() {
+        let vec = Vector3::new(1, 2, 3);
+        assert_eq!(vec.fold(0, |sum, c| sum + c), 6);
+        assert_eq!(vec.fold(1, |product, c| product * c), 6);
+        let joined = vec.fold(String::new(), |acc, c| format!("{acc}{c};"));
+        assert_eq!(joined, "1;2;3;");
+    }
+
+    #[test]
+    fn component_products() {
+        let a = Vector3::new(2.0, -3.0, 4.0);
+        let b = Vector3::new(0.5, 2.0, -8.0);
+        assert_eq!(a.component_mul(&b), a * b);
+        assert_eq!(a.component_mul(&b), Vector3::new(1.0, -6.0, -32.0));
+        assert_eq!(a.component_div(&b), a / b);
+        assert_eq!(a.component_div(&b), Vector3::new(4.0, -1.5, -0.5));
+        assert_eq!(
+            Vector3::new(7, 8, 9).component_div(&Vector3::new(2, 3, 4)),
+            Vector3::new(3, 2, 2)
+        );
+    }
+
+    #[test]
+    fn into_overloads() {
+        let vec = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(vec.dot_into((1.0, 0.0, 0.0)), 1.0);
+        assert_eq!(vec.dot_into([4.0, 5.0, 6.0]), 32.0);
+        assert_eq!(
+            vec.cross_into([0.0, 0.0, 1.0]),
+            vec.cross(&Vector3::new(0.0, 0.0, 1.0))
+        );
+        let right_angle = Vector3::new(1.0, 0.0, 0.0).angle_into((0.0, 2.0, 0.0));
+        assert!((right_angle - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn swizzles() {
+        let vec = Vector3::new(1, 2, 3);
+        assert_eq!(vec.swizzle(0, 2, 1), Some(Vector3::new(1, 3, 2)));
+        assert_eq!(vec.swizzle(2, 1, 0), Some(Vector3::new(3, 2, 1)));
+        assert_eq!(vec.swizzle(0, 0, 0), Some(Vector3::splat(1)));
+        assert_eq!(vec.swizzle(1, 1, 2), Some(Vector3::new(2, 2, 3)));
+        assert_eq!(vec.swizzle(0, 1, 3), None);
+    }
+
+    #[test]
+    fn reduce_components() {
+        let vec = Vector3::new(4.0, -1.5, 9.25);
+        assert_eq!(vec.reduce(f64::max), vec.max_element());
+        assert_eq!(vec.reduce(f64::min), vec.min_element());
+        assert_eq!(vec.reduce(|a, b| a + b), 11.75);
+        assert_eq!(Vector3::new(2, 3, 4).reduce(|a, b| a * b), 24);
+        let order = Vector3::new(1, 2, 3).reduce(|a, b| a * 10 + b);
+        assert_eq!(order, 123);
+    }
+
+    #[test]
+    fn predicates() {
+        let positive = Vector3::new(1.0, 2.0, 3.0);
+        let negative = Vector3::new(-1.0, -2.0, -3.0);
+        let mixed = Vector3::new(1.0, -2.0, 3.0);
+        let non_negative = |c: f64| c >= 0.0;
+
+        assert!(positive.all(non_negative));
+        assert!(positive.any(non_negative));
+        assert!(!negative.all(non_negative));
+        assert!(!negative.any(non_negative));
+        assert!(!mixed.all(non_negative));
+        assert!(mixed.any(non_negative));
+    }
+
+    #[test]
+    fn point_reflection() {
+        let point = Vector3::new(1.0, -2.0, 3.0);
+        assert_eq!(
+            point.reflect_across_point(&consts::VECTOR3_ZERO),
+            Vector3::new(-1.0, 2.0, -3.0)
+        );
+        assert_eq!(
+            point.reflect_across_point(&Vector3::new(2.0, 0.0, 1.0)),
+            Vector3::new(3.0, 2.0, -1.0)
+        );
+
+        let unsigned: Vector3<u32> = Vector3::new(1, 2, 3);
+        assert_eq!(
+            unsigned.reflect_across_point(&Vector3::new(4, 4, 4)),
+            Vector3::new(7, 6, 5)
+        );
+        assert_eq!(
+            Vector3::new(6u32, 4, 5).reflect_across_point(&Vector3::new(4, 4, 4)),
+            Vector3::new(2, 4, 3)
+        );
+
+        // `2 * center` alone would overflow here, but the results are in range.
+        let near_max = Vector3::new(i32::MAX - 4, i32::MAX, 0);
+        let center = Vector3::new(i32::MAX - 2, i32::MAX - 1, i32::MIN / 2);
+        assert_eq!(
+            near_max.reflect_across_point(&center),
+            Vector3::new(i32::MAX, i32::MAX - 2, i32::MIN)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn point_reflection_out_of_range() {
+        Vector3::new(3u8, 0, 0).reflect_across_point(&Vector3::new(1, 0, 0));
+    }
+
+    #[test]
+    fn outer() {
+        let vec1 = Vector3::new(1, 2, 3);
+        let vec2 = Vector3::new(4, 5, 6);
+        assert_eq!(
+            vec1.outer_product(&vec2),
+            [[4, 5, 6], [8, 10, 12], [12, 15, 18]]
+        );
+    }
+
     #[test]
     fn max_components() {
         let vec1 = Vector3::new(1.0, 5.0, 3.0);
@@ -276,6 +1536,236 @@ mod tests {
         assert_eq!(min_result, Vector3::new(1.0, 2.0, 3.0));
     }
 
+    #[test]
+    fn step_threshold() {
+        let value = Vector3::new(0.2, 0.5, 0.9);
+        let edge = Vector3::new(0.5, 0.5, 0.5);
+        assert_eq!(value.step(&edge), Vector3::new(0.0, 1.0, 1.0));
+
+        let value: Vector3<i32> = Vector3::new(-3, 4, 1);
+        assert_eq!(value.step(&Vector3::new(0, 5, 1)), Vector3::new(0, 0, 1));
+    }
+
+    #[test]
+    fn saturation() {
+        let color: Vector3<f64> = Vector3::new(-0.5, 0.25, 1.5);
+        assert_eq!(color.saturate(), Vector3::new(0.0, 0.25, 1.0));
+
+        let color: Vector3<f32> = Vector3::new(2.0, 1.0, -0.0);
+        assert_eq!(color.saturate(), Vector3::new(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn copy_sign() {
+        let magnitudes: Vector3<f64> = Vector3::new(1.0, -2.0, 3.0);
+        let signs = Vector3::new(-5.0, 7.0, -0.0);
+        assert_eq!(magnitudes.copysign(&signs), Vector3::new(-1.0, 2.0, -3.0));
+
+        let zeros: Vector3<f32> = Vector3::new(0.0, -0.0, 0.0);
+        let result = zeros.copysign(&Vector3::new(-1.0, 0.0, -0.0));
+        assert!(result.get_x().is_sign_negative());
+        assert!(result.get_y().is_sign_positive());
+        assert!(result.get_z().is_sign_negative());
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = [
+            Vector3::new(1, -2, 3),
+            Vector3::new(-4, 5, 0),
+            Vector3::new(2, 0, -1),
+        ];
+        assert_eq!(
+            Vector3::aabb(points),
+            Some((Vector3::new(-4, -2, -1), Vector3::new(2, 5, 3)))
+        );
+        let single = Vector3::new(0.5, 1.5, -2.5);
+        assert_eq!(Vector3::aabb([single]), Some((single, single)));
+        assert_eq!(Vector3::<f64>::aabb([]), None);
+    }
+
+    #[test]
+    fn componentwise_dominance() {
+        let low = Vector3::new(1, 2, 3);
+        let high = Vector3::new(1, 5, 4);
+        assert!(low.componentwise_le(&high));
+        assert!(high.componentwise_ge(&low));
+        assert!(!high.componentwise_le(&low));
+        assert!(!low.componentwise_ge(&high));
+        assert!(low.componentwise_le(&low) && low.componentwise_ge(&low));
+
+        // Lexicographically smaller, but incomparable component-wise.
+        let other = Vector3::new(0, 9, 0);
+        assert!(other < low);
+        assert!(!other.componentwise_le(&low));
+        assert!(!other.componentwise_ge(&low));
+    }
+
+    #[test]
+    fn aabb_containment() {
+        let min = Vector3::new(-1.0, 0.0, 2.0);
+        let max = Vector3::new(1.0, 4.0, 3.0);
+        let contains = |point| Vector3::aabb_contains(&min, &max, &point);
+        assert!(contains(Vector3::new(0.0, 2.0, 2.5)));
+        assert!(contains(min));
+        assert!(contains(max));
+        assert!(contains(Vector3::new(1.0, 2.0, 2.5)));
+        assert!(!contains(Vector3::new(1.5, 2.0, 2.5)));
+        assert!(!contains(Vector3::new(0.0, -0.1, 2.5)));
+        assert!(!contains(Vector3::new(0.0, 2.0, f64::NAN)));
+    }
+
+    #[test]
+    fn clamp_into_aabb() {
+        let min = Vector3::new(-1.0, 0.0, 2.0);
+        let max = Vector3::new(1.0, 4.0, 3.0);
+        let inside = Vector3::new(0.5, 1.0, 2.5);
+        assert_eq!(inside.clamp_to_aabb(&min, &max), inside);
+        assert_eq!(min.clamp_to_aabb(&min, &max), min);
+        assert_eq!(max.clamp_to_aabb(&min, &max), max);
+        assert_eq!(
+            Vector3::new(-5.0, 2.0, 10.0).clamp_to_aabb(&min, &max),
+            Vector3::new(-1.0, 2.0, 3.0)
+        );
+        assert_eq!(
+            Vector3::new(7, -7, 0).clamp_to_aabb(&Vector3::zero(), &Vector3::splat(5)),
+            Vector3::new(5, 0, 0)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn clamp_into_inverted_aabb() {
+        let corner = Vector3::new(1.0, 1.0, 1.0);
+        Vector3::zero().clamp_to_aabb(&corner, &Vector3::new(2.0, 0.0, 2.0));
+    }
+
+    #[test]
+    fn scalar_min_max() {
+        let vec = Vector3::new(-2.0, 0.5, 3.0);
+        assert_eq!(vec.min_scalar(1.0), Vector3::new(-2.0, 0.5, 1.0));
+        assert_eq!(vec.max_scalar(0.0), Vector3::new(0.0, 0.5, 3.0));
+        assert_eq!(vec.min_scalar(-5.0), Vector3::splat(-5.0));
+        assert_eq!(vec.max_scalar(5.0), Vector3::splat(5.0));
+        assert_eq!(Vector3::new(1u8, 9, 4).min_scalar(5), Vector3::new(1, 5, 4));
+    }
+
+    #[test]
+    fn min_max_components() {
+        let vec1 = Vector3::new(1.0, 5.0, 3.0);
+        let vec2 = Vector3::new(3.0, 2.0, 4.0);
+        assert_eq!(vec1.min_max(&vec2), (vec1.min(&vec2), vec1.max(&vec2)));
+        assert_eq!(vec2.min_max(&vec1), (vec1.min(&vec2), vec1.max(&vec2)));
+
+        // NaN and signed zeros compare unequal under `==`, so compare bit patterns.
+        let nan = Vector3::new(f64::NAN, 0.0, -0.0);
+        let other = Vector3::new(1.0, -0.0, 0.0);
+        for (a, b) in [(nan, other), (other, nan)] {
+            let (min, max) = a.min_max(&b);
+            assert!(min.structural_eq(&a.min(&b)));
+            assert!(max.structural_eq(&a.max(&b)));
+        }
+        assert_eq!(nan.min_max(&other).1.get_x(), 1.0);
+    }
+
+    #[test]
+    fn direction() {
+        let from: Vector3<f64> = Vector3::new(1.0, 1.0, 1.0);
+        let to = Vector3::new(1.0, 4.0, 5.0);
+        assert_eq!(from.direction_to(&to), Vector3::new(0.0, 0.6, 0.8));
+        assert_eq!(to.direction_to(&from), Vector3::new(0.0, -0.6, -0.8));
+        assert_eq!(from.direction_to(&from), Vector3::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn sorted() {
+        assert_eq!(Vector3::new(3, 1, 2).sorted_components(), [1, 2, 3]);
+        assert_eq!(
+            Vector3::new(3.0, 1.0, 2.0).sorted_components(),
+            [1.0, 2.0, 3.0]
+        );
+
+        let with_nan = Vector3::new(f64::NAN, -1.0, 2.0).sorted_components();
+        assert_eq!(&with_nan[..2], &[-1.0, 2.0]);
+        assert!(with_nan[2].is_nan());
+
+        let negative_nan = Vector3::new(1.0f32, -f32::NAN, -1.0).sorted_components();
+        assert!(negative_nan[0].is_nan() && negative_nan[0].is_sign_negative());
+        assert_eq!(&negative_nan[1..], &[-1.0, 1.0]);
+
+        let zeros = Vector3::new(0.0, -0.0, 0.0).sorted_components();
+        assert_eq!(zeros.map(f64::to_bits), [(-0.0f64).to_bits(), 0, 0]);
+    }
+
+    #[test]
+    fn sorted_matches_total_cmp() {
+        let values = [f64::NAN, -f64::NAN, -0.0, 0.0, 1.0, f64::NEG_INFINITY];
+        for &a in &values {
+            for &b in &values {
+                for &c in &values {
+                    let mut expected = [a, b, c];
+                    expected.sort_by(f64::total_cmp);
+                    let sorted = Vector3::new(a, b, c).sorted_components();
+                    assert_eq!(sorted.map(f64::to_bits), expected.map(f64::to_bits));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn elements() {
+        let vec: Vector3<i32> = Vector3::new(3, 1, 2);
+        assert_eq!(vec.min_element(), 1);
+        assert_eq!(vec.median_element(), 2);
+        assert_eq!(vec.max_element(), 3);
+        assert_eq!(Vector3::new(-0.5, 4.0, 0.25).median_element(), 0.25);
+    }
+
+    #[test]
+    fn fused_multiply_add() {
+        let vec: Vector3<f64> = Vector3::new(1.0, -2.0, 0.5);
+        let mul = Vector3::new(3.0, 4.0, -2.0);
+        let add = Vector3::new(0.5, 1.0, 1.0);
+        assert_eq!(vec.mul_add(&mul, &add), vec * mul + add);
+
+        // 0.1 * 10.0 rounds to exactly 1.0, but the fused path keeps the error.
+        let tenth: Vector3<f64> = Vector3::splat(0.1);
+        let fused = tenth.mul_add(&Vector3::splat(10.0), &Vector3::splat(-1.0));
+        assert_eq!(
+            tenth * Vector3::splat(10.0) - Vector3::splat(1.0),
+            Vector3::zero()
+        );
+        assert_eq!(fused, Vector3::splat(5.551115123125783e-17));
+    }
+
+    #[test]
+    fn length_clamping() {
+        let short: Vector3<f64> = Vector3::new(0.0, 0.6, 0.8);
+        assert_eq!(short.clamp_length(2.0, 5.0), Vector3::new(0.0, 1.2, 1.6));
+
+        let in_range: Vector3<f64> = Vector3::new(0.0, 3.0, 4.0);
+        assert_eq!(in_range.clamp_length(2.0, 5.0), in_range);
+
+        let long: Vector3<f32> = Vector3::new(0.0, 30.0, 40.0);
+        assert_eq!(long.clamp_length(2.0, 5.0), Vector3::new(0.0, 3.0, 4.0));
+
+        let zero: Vector3<f64> = Vector3::zero();
+        assert_eq!(zero.clamp_length(1.0, 5.0), zero);
+    }
+
+    #[test]
+    fn projection() {
+        let vec: Vector3<f64> = Vector3::new(1.0, 1.0, 1.0);
+        let normal = Vector3::new(0.0, 0.0, 2.0);
+        assert_eq!(vec.project_onto(&normal), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(vec.project_onto_plane(&normal), Vector3::new(1.0, 1.0, 0.0));
+        assert_eq!(
+            vec.project_onto(&Vector3::new(0.0, 0.0, 0.0)),
+            Vector3::new(0.0, 0.0, 0.0)
+        );
+    }
+
     #[test]
     fn fuzzy_equality() {
         let vec1 = Vector3::new(1.0, 2.0, 3.0);
@@ -287,8 +1777,42 @@ mod tests {
     #[test]
     fn nan_dont_panic() {
         let mut vec1: Vector3<f64> = Vector3::default();
-        vec1 /= std::f64::NAN;
+        vec1 /= f64::NAN;
+    }
+    #[test]
+    fn saturating_arithmetic() {
+        let max = Vector3::new(i32::MAX, i32::MAX - 1, 0);
+        let min = Vector3::new(i32::MIN, i32::MIN + 1, 0);
+        let step = Vector3::new(1, 2, 1);
+        assert_eq!(
+            max.saturating_add(&step),
+            Vector3::new(i32::MAX, i32::MAX, 1)
+        );
+        assert_eq!(
+            min.saturating_sub(&step),
+            Vector3::new(i32::MIN, i32::MIN, -1)
+        );
+
+        let unsigned: Vector3<u8> = Vector3::new(0, 1, 255);
+        let ones = Vector3::new(2u8, 2, 2);
+        assert_eq!(unsigned.saturating_sub(&ones), Vector3::new(0, 0, 253));
+        assert_eq!(unsigned.saturating_add(&ones), Vector3::new(2, 3, 255));
     }
+
+    #[test]
+    fn wrapping_arithmetic() {
+        let max = Vector3::new(i32::MAX, 0, 0);
+        let min = Vector3::new(i32::MIN, 0, 0);
+        let step = Vector3::new(1, 1, -1);
+        assert_eq!(max.wrapping_add(&step), Vector3::new(i32::MIN, 1, -1));
+        assert_eq!(min.wrapping_sub(&step), Vector3::new(i32::MAX, -1, 1));
+
+        let unsigned: Vector3<u8> = Vector3::new(0, 255, 10);
+        let ones = Vector3::new(1u8, 1, 1);
+        assert_eq!(unsigned.wrapping_sub(&ones), Vector3::new(255, 254, 9));
+        assert_eq!(unsigned.wrapping_add(&ones), Vector3::new(1, 0, 11));
+    }
+
     #[test]
     fn readme_example() {
         let mut v1: Vector3<f64> = Vector3::new(1.0, 2.0, 3.0);
@@ -51,6 +51,8 @@ impl<T: Vector3Coordinate> std::ops::Mul<T> for Vector3<T> {
     }
 }
 
+/// Multiplies component-wise (the Hadamard product), not the dot or cross product.
+/// See [`Vector3::component_mul`].
 impl<T: Vector3Coordinate> std::ops::Mul<Vector3<T>> for Vector3<T> {
     type Output = Self;
     fn mul(self, rhs: Vector3<T>) -> Self::Output {
@@ -89,6 +91,7 @@ impl<T: Vector3Coordinate> std::ops::Div<T> for Vector3<T> {
     }
 }
 
+/// Divides component-wise. See [`Vector3::component_div`].
 impl<T: Vector3Coordinate> std::ops::Div<Vector3<T>> for Vector3<T> {
     type Output = Self;
     fn div(self, rhs: Vector3<T>) -> Self::Output {
@@ -115,3 +118,70 @@ impl<T: Vector3Coordinate> std::ops::DivAssign<Vector3<T>> for Vector3<T> {
         self.z /= rhs.z;
     }
 }
+
+/// Computes the component-wise remainder.
+///
+/// For floating point coordinates this follows the semantics of `%` on `f32`/`f64`:
+/// the result has the same sign as the dividend and may therefore be negative.
+impl<T: Vector3Coordinate> std::ops::Rem<T> for Vector3<T> {
+    type Output = Self;
+    fn rem(self, rhs: T) -> Self::Output {
+        Vector3 {
+            x: self.x % rhs,
+            y: self.y % rhs,
+            z: self.z % rhs,
+        }
+    }
+}
+
+/// Computes the component-wise remainder against the matching component of `rhs`.
+///
+/// For floating point coordinates this follows the semantics of `%` on `f32`/`f64`:
+/// the result has the same sign as the dividend and may therefore be negative.
+impl<T: Vector3Coordinate> std::ops::Rem<Vector3<T>> for Vector3<T> {
+    type Output = Self;
+    fn rem(self, rhs: Vector3<T>) -> Self::Output {
+        Vector3 {
+            x: self.x % rhs.x,
+            y: self.y % rhs.y,
+            z: self.z % rhs.z,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::RemAssign<T> for Vector3<T> {
+    fn rem_assign(&mut self, rhs: T) {
+        *self = *self % rhs;
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::RemAssign<Vector3<T>> for Vector3<T> {
+    fn rem_assign(&mut self, rhs: Vector3<T>) {
+        *self = *self % rhs;
+    }
+}
+
+impl<T: Vector3Coordinate + std::ops::Neg<Output = T>> std::ops::Neg for Vector3<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Vector3 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::Mul<&T> for &Vector3<T> {
+    type Output = Vector3<T>;
+    fn mul(self, rhs: &T) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::Div<&T> for &Vector3<T> {
+    type Output = Vector3<T>;
+    fn div(self, rhs: &T) -> Self::Output {
+        *self / *rhs
+    }
+}
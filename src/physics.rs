@@ -0,0 +1,116 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::{consts, Vector3};
+
+impl Vector3<f64> {
+    /// Computes the Hooke's law force acting on this point from a spring attached
+    /// to `anchor`.
+    ///
+    /// The force points towards `anchor` when the spring is stretched beyond
+    /// `rest_length` and away from it when compressed, with a magnitude of
+    /// `stiffness * |distance - rest_length|`. Coincident points produce zero force.
+    pub fn spring_force(&self, anchor: &Self, rest_length: f64, stiffness: f64) -> Vector3<f64> {
+        let offset = *anchor - *self;
+        let distance = offset.magnitude();
+        if distance == 0.0 {
+            return consts::VECTOR3_ZERO;
+        }
+        offset * (stiffness * (distance - rest_length) / distance)
+    }
+
+    /// Computes the [`Vector3::spring_force`] with an additional damping term that
+    /// opposes this point's `velocity` along the spring axis.
+    ///
+    /// The damping term is `-damping * (velocity · axis) * axis`, where `axis` is the
+    /// unit direction from this point towards `anchor`. Coincident points produce
+    /// zero force.
+    pub fn damped_spring_force(
+        &self,
+        velocity: &Self,
+        anchor: &Self,
+        rest_length: f64,
+        stiffness: f64,
+        damping: f64,
+    ) -> Vector3<f64> {
+        let offset = *anchor - *self;
+        let distance = offset.magnitude();
+        if distance == 0.0 {
+            return consts::VECTOR3_ZERO;
+        }
+        let axis = offset / distance;
+        let spring = axis * (stiffness * (distance - rest_length));
+        spring - axis * (damping * velocity.dot(&axis))
+    }
+
+    /// Computes the inverse-square gravitational force on this point towards `other`,
+    /// `g * mass_product / r²` along the unit direction between them.
+    ///
+    /// Returns `None` for coincident points, where the force is singular.
+    pub fn gravitational_force(
+        &self,
+        other: &Self,
+        mass_product: f64,
+        g: f64,
+    ) -> Option<Vector3<f64>> {
+        let offset = *other - *self;
+        let distance_squared = offset.dot(&offset);
+        if distance_squared == 0.0 {
+            return None;
+        }
+        let distance = distance_squared.sqrt();
+        Some(offset * (g * mass_product / (distance_squared * distance)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stretched_spring() {
+        let point = Vector3::new(3.0, 0.0, 0.0);
+        let force = point.spring_force(&consts::VECTOR3_ZERO, 1.0, 2.0);
+        assert_eq!(force, Vector3::new(-4.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn compressed_spring() {
+        let point = Vector3::new(0.0, 0.5, 0.0);
+        let force = point.spring_force(&consts::VECTOR3_ZERO, 2.0, 4.0);
+        assert_eq!(force, Vector3::new(0.0, 6.0, 0.0));
+    }
+
+    #[test]
+    fn damped_spring() {
+        let point = Vector3::new(1.0, 0.0, 0.0);
+        let moving_away = Vector3::new(2.0, 0.0, 0.0);
+        let undamped = point.spring_force(&consts::VECTOR3_ZERO, 3.0, 1.0);
+        let damped = point.damped_spring_force(&moving_away, &consts::VECTOR3_ZERO, 3.0, 1.0, 0.5);
+        assert_eq!(undamped, Vector3::new(2.0, 0.0, 0.0));
+        assert_eq!(damped, Vector3::new(1.0, 0.0, 0.0));
+        assert!(damped.magnitude() < undamped.magnitude());
+
+        let still =
+            point.damped_spring_force(&consts::VECTOR3_ZERO, &consts::VECTOR3_ZERO, 3.0, 1.0, 0.5);
+        assert_eq!(still, undamped);
+    }
+
+    #[test]
+    fn gravity() {
+        let body = Vector3::new(0.0, 1.0, 0.0);
+        let force = body.gravitational_force(&consts::VECTOR3_ZERO, 6.0, 0.5);
+        assert_eq!(force, Some(Vector3::new(0.0, -3.0, 0.0)));
+
+        let far = Vector3::new(0.0, 0.0, 2.0);
+        let force = consts::VECTOR3_ZERO.gravitational_force(&far, 6.0, 0.5);
+        assert_eq!(force, Some(Vector3::new(0.0, 0.0, 0.75)));
+
+        assert_eq!(body.gravitational_force(&body, 6.0, 0.5), None);
+    }
+
+    #[test]
+    fn coincident_spring() {
+        let point = Vector3::new(1.0, 1.0, 1.0);
+        assert_eq!(point.spring_force(&point, 1.0, 10.0), consts::VECTOR3_ZERO);
+    }
+}
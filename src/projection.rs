@@ -0,0 +1,72 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::Vector3;
+
+impl Vector3<f64> {
+    /// Projects this point onto the 2D screen basis spanned by `right` and `up`,
+    /// returning `(self · right, self · up)`.
+    pub fn orthographic_project(&self, right: &Vector3<f64>, up: &Vector3<f64>) -> (f64, f64) {
+        (self.dot(right), self.dot(up))
+    }
+
+    /// Projects this camera-space point onto the image plane at `focal_length`,
+    /// treating `z` as depth and returning `(x * f / z, y * f / z)`.
+    ///
+    /// Returns `None` for points at or behind the camera plane (`z <= 0`).
+    pub fn perspective_project(&self, focal_length: f64) -> Option<(f64, f64)> {
+        if self.z <= 0.0 {
+            return None;
+        }
+        let scale = focal_length / self.z;
+        Some((self.x * scale, self.y * scale))
+    }
+
+    /// Computes the normalized camera-space ray direction through a screen
+    /// coordinate, inverting [`Vector3::perspective_project`].
+    ///
+    /// The camera looks down `+z`, so the screen center `(0, 0)` maps to the Z-axis.
+    pub fn ray_from_screen(screen_x: f64, screen_y: f64, focal_length: f64) -> Vector3<f64> {
+        let mut ray = Vector3::new(screen_x, screen_y, focal_length);
+        ray.normalize();
+        ray
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts;
+
+    #[test]
+    fn orthographic_projection() {
+        let screen = consts::X_AXIS.orthographic_project(&consts::X_AXIS, &consts::Y_AXIS);
+        assert_eq!(screen, (1.0, 0.0));
+
+        let point = Vector3::new(2.0, -3.0, 7.0);
+        assert_eq!(
+            point.orthographic_project(&consts::X_AXIS, &consts::Y_AXIS),
+            (2.0, -3.0)
+        );
+    }
+
+    #[test]
+    fn perspective_projection() {
+        let in_front = Vector3::new(2.0, -4.0, 4.0);
+        assert_eq!(in_front.perspective_project(2.0), Some((1.0, -2.0)));
+
+        let behind = Vector3::new(2.0, -4.0, -4.0);
+        assert_eq!(behind.perspective_project(2.0), None);
+        assert_eq!(Vector3::new(1.0, 1.0, 0.0).perspective_project(2.0), None);
+    }
+
+    #[test]
+    fn screen_ray() {
+        assert_eq!(Vector3::ray_from_screen(0.0, 0.0, 1.5), consts::Z_AXIS);
+
+        let point = Vector3::new(3.0, -1.0, 6.0);
+        let (screen_x, screen_y) = point.perspective_project(2.0).unwrap();
+        let mut direction = point;
+        direction.normalize();
+        assert!(Vector3::ray_from_screen(screen_x, screen_y, 2.0).fuzzy_equal(&direction, 1e-12));
+    }
+}
@@ -0,0 +1,110 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::Vector3;
+
+/// A `Vector3<f64>` that is guaranteed to have unit length.
+///
+/// It can only be built through [`Vector3::try_normalize`], and only exposes
+/// operations that cannot break the invariant. Anything else, such as scaling,
+/// consumes it back into a plain [`Vector3`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct UnitVector3(Vector3<f64>);
+
+impl Vector3<f64> {
+    /// Normalizes this vector into a [`UnitVector3`], or returns `None` when its
+    /// magnitude is zero or not finite.
+    ///
+    /// Besides the zero vector and vectors with non-finite components, this
+    /// rejects finite vectors whose squared magnitude overflows to infinity, which
+    /// happens once a component exceeds `f64::MAX.sqrt()` (about `1.3e154`).
+    pub fn try_normalize(&self) -> Option<UnitVector3> {
+        let length = self.magnitude();
+        if length == 0.0 || !length.is_finite() {
+            return None;
+        }
+        Some(UnitVector3(*self / length))
+    }
+}
+
+impl UnitVector3 {
+    /// Returns the underlying unit-length vector.
+    pub const fn into_inner(self) -> Vector3<f64> {
+        self.0
+    }
+
+    /// Borrows the underlying unit-length vector.
+    pub const fn as_vector(&self) -> &Vector3<f64> {
+        &self.0
+    }
+
+    /// Computes the dot product with another unit vector, which is the cosine of
+    /// the angle between them.
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.0.dot(&other.0)
+    }
+
+    /// Computes the cross product with another unit vector. The result is only
+    /// unit length for perpendicular inputs, so it is returned as a plain vector.
+    pub fn cross(&self, other: &Self) -> Vector3<f64> {
+        self.0.cross(&other.0)
+    }
+
+    /// Computes the angle in radians between two unit vectors.
+    pub fn angle(&self, other: &Self) -> f64 {
+        // Rounding can push the cosine slightly outside [-1, 1], where acos is NaN.
+        self.dot(other).clamp(-1.0, 1.0).acos()
+    }
+}
+
+impl From<UnitVector3> for Vector3<f64> {
+    fn from(value: UnitVector3) -> Self {
+        value.into_inner()
+    }
+}
+
+impl std::ops::Neg for UnitVector3 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        UnitVector3(-self.0)
+    }
+}
+
+impl std::ops::Mul<f64> for UnitVector3 {
+    type Output = Vector3<f64>;
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.0 * rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts;
+
+    #[test]
+    fn construction() {
+        assert_eq!(consts::VECTOR3_ZERO.try_normalize(), None);
+        assert_eq!(Vector3::new(f64::NAN, 1.0, 0.0).try_normalize(), None);
+        assert_eq!(Vector3::new(f64::INFINITY, 0.0, 0.0).try_normalize(), None);
+        assert_eq!(Vector3::splat(f64::MAX).try_normalize(), None);
+        assert_eq!(Vector3::new(f64::MAX, 0.0, 0.0).try_normalize(), None);
+
+        let unit = Vector3::new(0.0, 3.0, 4.0).try_normalize().unwrap();
+        assert_eq!(unit.into_inner(), Vector3::new(0.0, 0.6, 0.8));
+        assert!((unit.as_vector().magnitude() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn unit_math() {
+        let unit = Vector3::new(1.0, -2.0, 5.0).try_normalize().unwrap();
+        assert!((unit.dot(&unit) - 1.0).abs() < 1e-12);
+        assert_eq!(unit.angle(&unit), 0.0);
+        assert!((unit.angle(&-unit) - std::f64::consts::PI).abs() < 1e-12);
+
+        let x = consts::X_AXIS.try_normalize().unwrap();
+        let y = consts::Y_AXIS.try_normalize().unwrap();
+        assert_eq!(x.cross(&y), consts::Z_AXIS);
+        assert_eq!(x * 2.0, Vector3::new(2.0, 0.0, 0.0));
+        assert_eq!(Vector3::from(y), consts::Y_AXIS);
+    }
+}
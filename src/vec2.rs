@@ -0,0 +1,212 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::{Vector3, Vector3Coordinate};
+
+/// Represents a vector in 2D space.
+#[derive(Debug, PartialOrd, PartialEq, Default, Clone, Copy)]
+pub struct Vector2<T: Vector3Coordinate> {
+    x: T,
+    y: T,
+}
+
+impl<T: Vector3Coordinate> Vector2<T> {
+    /// Creates a new Vector2 with the specified coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vec3_rs::vec2::Vector2;
+    ///
+    /// let vector2 = Vector2::new(1.0, 2.0);
+    /// ```
+    pub const fn new(x: T, y: T) -> Self {
+        Vector2 { x, y }
+    }
+
+    /// Computes the magnitude (length) of the vector.
+    pub fn magnitude(&self) -> f64 {
+        let mag2 = self.x * self.x + self.y * self.y;
+        mag2.to_f64().expect("f64 should handle all values").sqrt()
+    }
+
+    /// Computes the dot product between this vector and another vector.
+    pub fn dot(&self, target: &Self) -> T {
+        self.x * target.x + self.y * target.y
+    }
+
+    /// Promotes this vector to 3D by appending the given Z component.
+    pub fn extend(&self, z: T) -> Vector3<T> {
+        Vector3::new(self.x, self.y, z)
+    }
+
+    /// Retrieves the X component of the vector.
+    pub const fn get_x(&self) -> T {
+        self.x
+    }
+
+    /// Retrieves the Y component of the vector.
+    pub const fn get_y(&self) -> T {
+        self.y
+    }
+}
+
+impl<T: Vector3Coordinate> Vector3<T> {
+    /// Projects this vector to 2D by dropping the Z component.
+    pub fn truncate(&self) -> Vector2<T> {
+        Vector2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::fmt::Display for Vector2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Vector2({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::Add<Vector2<T>> for Vector2<T> {
+    type Output = Self;
+    fn add(self, rhs: Vector2<T>) -> Self::Output {
+        Vector2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::AddAssign<Vector2<T>> for Vector2<T> {
+    fn add_assign(&mut self, rhs: Vector2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::Sub<Vector2<T>> for Vector2<T> {
+    type Output = Self;
+    fn sub(self, rhs: Vector2<T>) -> Self::Output {
+        Vector2 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::SubAssign<Vector2<T>> for Vector2<T> {
+    fn sub_assign(&mut self, rhs: Vector2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::Mul<T> for Vector2<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        Vector2 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::Mul<Vector2<T>> for Vector2<T> {
+    type Output = Self;
+    fn mul(self, rhs: Vector2<T>) -> Self::Output {
+        Vector2 {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::MulAssign<T> for Vector2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::MulAssign<Vector2<T>> for Vector2<T> {
+    fn mul_assign(&mut self, rhs: Vector2<T>) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::Div<T> for Vector2<T> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output {
+        Vector2 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::Div<Vector2<T>> for Vector2<T> {
+    type Output = Self;
+    fn div(self, rhs: Vector2<T>) -> Self::Output {
+        Vector2 {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::DivAssign<T> for Vector2<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl<T: Vector3Coordinate> std::ops::DivAssign<Vector2<T>> for Vector2<T> {
+    fn div_assign(&mut self, rhs: Vector2<T>) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let my_vec = Vector2::new(1.3, -5.35501);
+        assert_eq!(my_vec.get_x(), 1.3);
+        assert_eq!(my_vec.get_y(), -5.35501);
+    }
+
+    #[test]
+    fn truncate_extend_round_trip() {
+        let vec3 = Vector3::new(1, -2, 3);
+        let vec2 = vec3.truncate();
+        assert_eq!(vec2, Vector2::new(1, -2));
+        assert_eq!(vec2.extend(3), vec3);
+        assert_eq!(vec2.extend(7).truncate(), vec2);
+    }
+
+    #[test]
+    fn basic_ops() {
+        let vec1 = Vector2::new(3.0, 4.0);
+        let vec2 = Vector2::new(1.0, -2.0);
+        assert_eq!(vec1.magnitude(), 5.0);
+        assert_eq!(vec1.dot(&vec2), -5.0);
+        assert_eq!(vec1 + vec2, Vector2::new(4.0, 2.0));
+        assert_eq!(vec1 - vec2, Vector2::new(2.0, 6.0));
+        assert_eq!(vec1 * 2.0, Vector2::new(6.0, 8.0));
+        assert_eq!(vec1 * vec2, Vector2::new(3.0, -8.0));
+        assert_eq!(vec1 / 2.0, Vector2::new(1.5, 2.0));
+        assert_eq!(vec1 / vec2, Vector2::new(3.0, -2.0));
+
+        let mut vec3 = vec1;
+        vec3 += vec2;
+        vec3 -= vec1;
+        vec3 *= 3.0;
+        vec3 /= Vector2::new(1.0, 2.0);
+        assert_eq!(vec3, Vector2::new(3.0, -3.0));
+        assert_eq!(vec3.to_string(), "Vector2(3, -3)");
+    }
+}
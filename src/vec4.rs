@@ -0,0 +1,92 @@
+#![deny(unsafe_code, warnings, clippy::all)]
+
+use crate::{Vector3, Vector3Coordinate};
+
+/// Represents a vector in 4D space, typically a homogeneous coordinate.
+#[derive(Debug, PartialOrd, PartialEq, Default, Clone, Copy)]
+pub struct Vector4<T: Vector3Coordinate> {
+    x: T,
+    y: T,
+    z: T,
+    w: T,
+}
+
+impl<T: Vector3Coordinate> Vector4<T> {
+    /// Creates a new Vector4 with the specified coordinates.
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Vector4 { x, y, z, w }
+    }
+
+    /// Retrieves the X component of the vector.
+    pub const fn get_x(&self) -> T {
+        self.x
+    }
+
+    /// Retrieves the Y component of the vector.
+    pub const fn get_y(&self) -> T {
+        self.y
+    }
+
+    /// Retrieves the Z component of the vector.
+    pub const fn get_z(&self) -> T {
+        self.z
+    }
+
+    /// Retrieves the W component of the vector.
+    pub const fn get_w(&self) -> T {
+        self.w
+    }
+}
+
+impl<T: Vector3Coordinate + num::Float> Vector4<T> {
+    /// Converts this homogeneous coordinate back to 3D by dividing x, y and z by w
+    /// (the perspective divide).
+    ///
+    /// A `w` of zero describes a point at infinity; following IEEE float division,
+    /// the resulting components are infinite, or `NaN` where the numerator is also zero.
+    pub fn to_cartesian(&self) -> Vector3<T> {
+        Vector3::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
+}
+
+impl<T: Vector3Coordinate> Vector3<T> {
+    /// Extends this vector to a homogeneous coordinate with the given `w` component.
+    pub fn to_homogeneous(&self, w: T) -> Vector4<T> {
+        Vector4 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w,
+        }
+    }
+}
+
+impl<T: Vector3Coordinate> std::fmt::Display for Vector4<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Vector4({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn homogeneous_round_trip() {
+        let point = Vector3::new(1.5, -2.0, 3.0);
+        let homogeneous = point.to_homogeneous(1.0);
+        assert_eq!(homogeneous, Vector4::new(1.5, -2.0, 3.0, 1.0));
+        assert_eq!(homogeneous.to_cartesian(), point);
+    }
+
+    #[test]
+    fn perspective_divide() {
+        let clip = Vector4::new(2.0, 4.0, -6.0, 2.0);
+        assert_eq!(clip.to_cartesian(), Vector3::new(1.0, 2.0, -3.0));
+
+        let at_infinity = Vector4::new(1.0, -1.0, 0.0, 0.0).to_cartesian();
+        assert_eq!(at_infinity.get_x(), f64::INFINITY);
+        assert_eq!(at_infinity.get_y(), f64::NEG_INFINITY);
+        assert!(at_infinity.get_z().is_nan());
+    }
+}